@@ -0,0 +1,104 @@
+//! Hashes the code shared between days for the answer cache, sets the `any_day` cfg if any day is
+//! compiled in, and with the `embed-inputs` feature, generates the table of inputs and answers
+//! that the runner includes from `runner/embedded.rs`.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+	println!("cargo:rerun-if-changed=build.rs");
+	shared_source_hash();
+
+	// Lets code that only matters when some day is compiled in be left out without any
+	println!("cargo:rustc-check-cfg=cfg(any_day)");
+	let any_day = env::vars().any(|(key, _)| {
+		key.strip_prefix("CARGO_FEATURE_DAY")
+			.is_some_and(|n| n.len() == 2 && n.bytes().all(|b| b.is_ascii_digit()))
+	});
+	if any_day {
+		println!("cargo:rustc-cfg=any_day");
+	}
+
+	if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_none() {
+		return;
+	}
+
+	let inputs = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("inputs");
+	println!("cargo:rerun-if-changed={}", inputs.display());
+
+	let mut table = String::from("[\n");
+	for day in 1..=25 {
+		let dir = inputs.join(format!("day{day:02}"));
+		let input = dir.join("input.txt");
+		if !input.is_file() {
+			table += "\tNone,\n";
+			continue;
+		}
+		let answers = dir.join("answer.txt");
+		let answers = if answers.is_file() {
+			format!("Some(include_str!({answers:?}))")
+		} else {
+			"None".to_string()
+		};
+		writeln!(
+			table,
+			"\tSome(Embedded {{ input: include_bytes!({input:?}), answers: {answers} }}),"
+		)
+		.unwrap();
+	}
+	table += "]\n";
+
+	let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("embedded_inputs.rs");
+	fs::write(out, table).unwrap();
+}
+
+/// Hashes everything in `src/` except the day modules, along with `Cargo.lock` and the enabled
+/// features, so cached answers are dropped when shared code, a dependency, or the build changes.
+/// Each day's own module is hashed by the runner, so changing one day doesn't drop the others.
+fn shared_source_hash() {
+	let root = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap());
+	println!("cargo:rerun-if-changed=src");
+	println!("cargo:rerun-if-changed=Cargo.lock");
+
+	let mut files = vec![root.join("Cargo.lock")];
+	collect_files(&root.join("src"), &root.join("src/days"), &mut files);
+	files.sort();
+
+	let mut features: Vec<_> = env::vars()
+		.filter_map(|(key, _)| key.strip_prefix("CARGO_FEATURE_").map(str::to_string))
+		.collect();
+	features.sort();
+
+	// 64-bit FNV-1a, the same hash the cache uses
+	let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+	let mut add = |bytes: &[u8]| {
+		for &b in bytes {
+			hash = (hash ^ b as u64).wrapping_mul(0x0100_0000_01b3);
+		}
+	};
+	for file in &files {
+		let name = file.strip_prefix(&root).unwrap().to_string_lossy();
+		add(name.as_bytes());
+		add(&fs::read(file).unwrap_or_default());
+	}
+	add(features.join(",").as_bytes());
+
+	let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("shared_source_hash.rs");
+	fs::write(out, format!("{hash:#x}\n")).unwrap();
+}
+
+fn collect_files(dir: &Path, skip: &Path, files: &mut Vec<PathBuf>) {
+	for entry in fs::read_dir(dir).unwrap().flatten() {
+		let path = entry.path();
+		if path == skip {
+			continue;
+		}
+		if path.is_dir() {
+			collect_files(&path, skip, files);
+		} else {
+			files.push(path);
+		}
+	}
+}
@@ -0,0 +1,51 @@
+//! Picks the day and part from the `AOC_DAY` and `AOC_PART` environment variables and generates
+//! the constants and `run` function for them.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn main() {
+	println!("cargo:rerun-if-env-changed=AOC_DAY");
+	println!("cargo:rerun-if-env-changed=AOC_PART");
+
+	let day = read_var("AOC_DAY", 1);
+	let part = read_var("AOC_PART", 1);
+	if !(1..=25).contains(&day) {
+		panic!("AOC_DAY must be in 1..=25, got {day}");
+	}
+	let method = match part {
+		1 => "part_one",
+		2 => "part_two",
+		_ => panic!("AOC_PART must be 1 or 2, got {part}"),
+	};
+
+	let source = format!(
+		"\
+/// The day that [`run`] solves, set with `AOC_DAY`.
+pub const DAY: u32 = {day};
+/// The part that [`run`] solves, set with `AOC_PART`.
+pub const PART: u32 = {part};
+
+/// Solves part {part} of day {day} for `input`.
+pub fn run(input: &str) -> impl ::std::fmt::Display {{
+	use ::aoc2023::Solver;
+	let mut solver = ::aoc2023::days::day{day:02}::Solution::initialize(input.as_bytes().to_vec(), 0);
+	solver.{method}(0, &::aoc2023::Context::new())
+}}
+"
+	);
+
+	let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("selected.rs");
+	fs::write(out, source).unwrap();
+}
+
+fn read_var(name: &str, default: u32) -> u32 {
+	match env::var(name) {
+		Ok(value) => value
+			.trim()
+			.parse()
+			.unwrap_or_else(|_| panic!("{name} must be a number, got {value:?}")),
+		Err(_) => default,
+	}
+}
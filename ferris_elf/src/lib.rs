@@ -0,0 +1,11 @@
+//! A single part of a single day, chosen at compile time, for harnesses that time one part at a
+//! time like `examples/runner.rs`.
+//!
+//! The day and part are read from the `AOC_DAY` and `AOC_PART` environment variables when the
+//! crate is built, and both default to 1. For example, from the repository root:
+//!
+//! ```sh
+//! AOC_DAY=3 AOC_PART=2 cargo run --release -p ferris_elf --example runner
+//! ```
+
+include!(concat!(env!("OUT_DIR"), "/selected.rs"));
@@ -0,0 +1,7 @@
+//! A single day built as a `cdylib` that the runner's hot mode loads and reloads, so changing a
+//! day only rebuilds the library crate with that one day in it instead of the whole runner.
+//!
+//! The day is picked with its feature, and the exported functions are described in
+//! [`aoc2023::hot`].
+
+aoc2023::export_hot_day!();
@@ -0,0 +1,168 @@
+//! The answer to a part. Solvers return anything that converts into an [`Answer`], and the runner
+//! compares and stores answers as values instead of as their printed text.
+
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+/// The answer to a part.
+///
+/// Integers always use [`Answer::Integer`] when they fit in an `i64`, so answers that are equal as
+/// numbers compare equal whatever type the solver used.
+///
+/// ```
+/// # use aoc2023::Answer;
+/// assert_eq!(Answer::from(42u32), Answer::from(42i64));
+/// assert_ne!(Answer::from(0), Answer::None);
+/// assert_ne!(Answer::from(0), Answer::from("0"));
+/// assert_eq!(Answer::from(u64::MAX), Answer::BigInteger(u64::MAX as i128));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum Answer {
+	/// The part has no answer.
+	#[default]
+	None,
+	Integer(i64),
+	/// An integer that doesn't fit in an `i64`.
+	BigInteger(i128),
+	/// A single line of text.
+	Text(String),
+	/// Several lines of text, like letters drawn on a grid.
+	Grid(String),
+}
+
+impl Answer {
+	pub fn is_none(&self) -> bool {
+		matches!(self, Answer::None)
+	}
+
+	/// Parses a line of an answer file, as written by [`Answer::to_line`].
+	///
+	/// ```
+	/// # use aoc2023::Answer;
+	/// for answer in [
+	///     Answer::None,
+	///     Answer::Integer(-7),
+	///     Answer::BigInteger(1 << 100),
+	///     Answer::Text("ABC".to_string()),
+	///     Answer::Text("123".to_string()),
+	///     Answer::Grid("#.\n.#".to_string()),
+	/// ] {
+	///     assert_eq!(Answer::parse_line(&answer.to_line()), answer);
+	/// }
+	/// ```
+	pub fn parse_line(line: &str) -> Self {
+		if line.starts_with('"') {
+			if let Ok(text) = serde_json::from_str(line) {
+				return Answer::Text(text);
+			}
+		}
+
+		if line.is_empty() {
+			Answer::None
+		} else if let Ok(n) = line.parse::<i128>() {
+			Answer::from(n)
+		} else if line.contains("\\n") {
+			Answer::Grid(line.replace("\\n", "\n"))
+		} else {
+			Answer::Text(line.to_string())
+		}
+	}
+
+	/// Formats the answer as a single line for an answer file. Grids have their line breaks
+	/// escaped as `\n`, and text that would be read back as something else, like `"123"`, is
+	/// written as a quoted JSON string.
+	pub fn to_line(&self) -> String {
+		match self {
+			Answer::Grid(grid) => grid.replace('\n', "\\n"),
+			Answer::Text(text) if Answer::parse_line(text) != *self => {
+				serde_json::to_string(text).expect("strings always serialize")
+			}
+			answer => answer.to_string(),
+		}
+	}
+}
+
+impl Display for Answer {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Answer::None => Ok(()),
+			Answer::Integer(n) => n.fmt(f),
+			Answer::BigInteger(n) => n.fmt(f),
+			Answer::Text(text) | Answer::Grid(text) => text.fmt(f),
+		}
+	}
+}
+
+macro_rules! from_integer {
+	($($t:ty),*) => {
+		$(impl From<$t> for Answer {
+			fn from(n: $t) -> Self {
+				match i64::try_from(n) {
+					Ok(n) => Answer::Integer(n),
+					Err(_) => Answer::BigInteger(n as i128),
+				}
+			}
+		})*
+	};
+}
+from_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, i128);
+
+impl From<String> for Answer {
+	fn from(text: String) -> Self {
+		if text.contains('\n') {
+			Answer::Grid(text)
+		} else {
+			Answer::Text(text)
+		}
+	}
+}
+
+impl From<&str> for Answer {
+	fn from(text: &str) -> Self {
+		Answer::from(text.to_string())
+	}
+}
+
+impl From<char> for Answer {
+	fn from(c: char) -> Self {
+		Answer::Text(c.to_string())
+	}
+}
+
+impl From<()> for Answer {
+	fn from(_: ()) -> Self {
+		Answer::None
+	}
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+	fn from(answer: Option<T>) -> Self {
+		answer.map_or(Answer::None, Into::into)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn text_round_trips() {
+		for text in [
+			"123",
+			"-5",
+			"",
+			"\"quoted\"",
+			"\"open",
+			"a\\nb",
+			"It's snowing ❄️",
+		] {
+			let answer = Answer::Text(text.to_string());
+			assert_eq!(Answer::parse_line(&answer.to_line()), answer, "{text:?}");
+		}
+		// Plain text is still written as is, so existing answer files keep working
+		assert_eq!(Answer::from("ABC").to_line(), "ABC");
+		assert_eq!(Answer::Text("123".to_string()).to_line(), "\"123\"");
+	}
+}
@@ -0,0 +1,61 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Handle passed into [`Solver`](crate::Solver) parts for reporting progress and checking for
+/// cancellation.
+///
+/// Cloning a `Context` gives another handle to the same state, so the runner can keep one to
+/// watch while the solver uses the other. All methods take `&self` and can be called from rayon
+/// workers.
+#[derive(Debug, Default, Clone)]
+pub struct Context {
+	inner: Arc<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+	done: AtomicU64,
+	total: AtomicU64,
+	cancelled: AtomicBool,
+}
+
+impl Context {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Sets the amount of work done and the total amount of work. The units are up to the solver.
+	pub fn progress(&self, done: u64, total: u64) {
+		self.inner.total.store(total, Ordering::Relaxed);
+		self.inner.done.store(done, Ordering::Relaxed);
+	}
+
+	/// Sets the total amount of work without changing the amount done.
+	pub fn set_total(&self, total: u64) {
+		self.inner.total.store(total, Ordering::Relaxed);
+	}
+
+	/// Adds to the amount of work done. Useful when several threads each finish a piece.
+	pub fn add_progress(&self, done: u64) {
+		self.inner.done.fetch_add(done, Ordering::Relaxed);
+	}
+
+	/// Returns `(done, total)`. `total` is 0 if the solver never reported any progress.
+	pub fn get_progress(&self) -> (u64, u64) {
+		(
+			self.inner.done.load(Ordering::Relaxed),
+			self.inner.total.load(Ordering::Relaxed),
+		)
+	}
+
+	/// Returns true once the runner has asked the solver to stop. Solvers should return their best
+	/// answer so far when they see this.
+	pub fn is_cancelled(&self) -> bool {
+		self.inner.cancelled.load(Ordering::Relaxed)
+	}
+
+	/// Asks the solver to stop.
+	pub fn cancel(&self) {
+		self.inner.cancelled.store(true, Ordering::Relaxed);
+	}
+}
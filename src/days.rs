@@ -23,3 +23,56 @@ pub mod day22;
 pub mod day23;
 pub mod day24;
 pub mod day25;
+
+/// Calls `$mac` with every day's number, module, and cargo feature, like
+/// `01 => day01 if "day01",`. This is the only list of days, so adding a day means adding its
+/// `mod` above and a line here. `--mode new` does both.
+///
+/// Each day module starts with `#![cfg(feature = "dayNN")]`, so `$mac` has to leave out days whose
+/// feature is disabled, usually with `#[cfg(feature = $feature)]` on each arm.
+macro_rules! with_days {
+	($mac:ident) => {
+		$mac! {
+			01 => day01 if "day01",
+			02 => day02 if "day02",
+			03 => day03 if "day03",
+			04 => day04 if "day04",
+			05 => day05 if "day05",
+			06 => day06 if "day06",
+			07 => day07 if "day07",
+			08 => day08 if "day08",
+			09 => day09 if "day09",
+			10 => day10 if "day10",
+			11 => day11 if "day11",
+			12 => day12 if "day12",
+			13 => day13 if "day13",
+			14 => day14 if "day14",
+			15 => day15 if "day15",
+			16 => day16 if "day16",
+			17 => day17 if "day17",
+			18 => day18 if "day18",
+			19 => day19 if "day19",
+			20 => day20 if "day20",
+			21 => day21 if "day21",
+			22 => day22 if "day22",
+			23 => day23 if "day23",
+			24 => day24 if "day24",
+			25 => day25 if "day25",
+		}
+	};
+}
+pub(crate) use with_days;
+
+/// Returns whether `day` is a day whose cargo feature is enabled, so its solution is compiled in.
+#[allow(clippy::zero_prefixed_literal)]
+pub fn is_compiled(day: u32) -> bool {
+	macro_rules! compiled_match {
+		($($num:literal => $day:ident if $feature:literal,)*) => {
+			match day {
+				$($num => cfg!(feature = $feature),)*
+				_ => false,
+			}
+		};
+	}
+	with_days!(compiled_match)
+}
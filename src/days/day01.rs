@@ -1,7 +1,9 @@
+#![cfg(feature = "day01")]
+
 use crate::helpers::*;
 
-pub type A1 = impl Display + Debug + Clone;
-pub type A2 = impl Display + Debug + Clone;
+pub type A1 = u64;
+pub type A2 = u64;
 
 #[derive(Debug, Default, Clone)]
 pub struct Solution {
@@ -16,7 +18,7 @@ impl Solver for Solution {
 		Self { file }
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		let mut total = 0;
 		let mut first = 0;
 		let mut last = 0;
@@ -38,7 +40,7 @@ impl Solver for Solution {
 		total
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		let mut total = 0;
 		let mut first = 0;
 		let mut last = 0;
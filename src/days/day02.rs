@@ -1,7 +1,9 @@
+#![cfg(feature = "day02")]
+
 use crate::helpers::*;
 
-pub type A1 = impl Display + Debug + Clone;
-pub type A2 = impl Display + Debug + Clone;
+pub type A1 = usize;
+pub type A2 = u64;
 
 #[derive(Debug, Default, Clone)]
 pub struct Solution {
@@ -16,13 +18,13 @@ impl Solver for Solution {
 		let games = file
 			.lines()
 			.map(|line| {
-				let (_, line) = line.split_once(is(b' ')).unwrap();
-				let (_id, line) = line.split_once(is(b':')).unwrap();
+				let (_, line) = line.split_once_by(is(b' ')).unwrap();
+				let (_id, line) = line.split_once_by(is(b':')).unwrap();
 				line.delimiter(';')
 					.map(|round| {
 						let cubes = round
 							.delimiter(',')
-							.map(|cubes| cubes[1..].split_once(is(b' ')).unwrap());
+							.map(|cubes| cubes[1..].split_once_by(is(b' ')).unwrap());
 						let mut red = 0;
 						let mut green = 0;
 						let mut blue = 0;
@@ -43,7 +45,7 @@ impl Solver for Solution {
 		Self { games }
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		// dbg_small!(self);
 		let valid_counts = [12, 13, 14];
 
@@ -61,7 +63,7 @@ impl Solver for Solution {
 			.sum_self()
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		let mut total = 0;
 		for game in &self.games {
 			let mut red = 0;
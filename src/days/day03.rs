@@ -1,9 +1,11 @@
+#![cfg(feature = "day03")]
+
 use std::cell::Cell;
 
 use crate::helpers::*;
 
-pub type A1 = impl Display + Debug + Clone;
-pub type A2 = impl Display + Debug + Clone;
+pub type A1 = u32;
+pub type A2 = u32;
 
 #[derive(Debug, Default, Clone)]
 pub struct Solution {
@@ -22,7 +24,7 @@ impl Solver for Solution {
 		Self { file }
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		let mut total = 0;
 		for (y, row) in self.file.iter().enumerate() {
 			for (x, &Item(item, _)) in row.iter().enumerate() {
@@ -62,7 +64,7 @@ impl Solver for Solution {
 		total
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		let mut total = 0;
 		for (y, row) in self.file.iter().enumerate() {
 			for (x, &Item(item, _)) in row.iter().enumerate() {
@@ -1,7 +1,9 @@
+#![cfg(feature = "day04")]
+
 use crate::helpers::*;
 
-pub type A1 = impl Display + Debug + Clone;
-pub type A2 = impl Display + Debug + Clone;
+pub type A1 = u32;
+pub type A2 = u64;
 
 #[derive(Debug, Default, Clone)]
 pub struct Solution {
@@ -41,14 +43,14 @@ impl Solver for Solution {
 		Self { file }
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		self.file
 			.iter()
 			.map(|&count| 2u32.pow(count as _) / 2)
 			.sum_self()
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		let mut copies = [1u64; 10];
 		self.file
 			.iter()
@@ -1,3 +1,5 @@
+#![cfg(feature = "day05")]
+
 use std::convert::identity;
 
 use crate::helpers::*;
@@ -33,7 +35,8 @@ impl Solver for Solution {
 					.filter_empty()
 					.filter(|c| c[0].is_ascii_digit())
 					.map(|item| item.parse().unwrap())
-					.array_chunks()
+					.tuples()
+					.map(|(a, b, c)| [a, b, c])
 					.sorted_by_key(|[_, b, _]| *b)
 					.collect_vec()
 			})
@@ -42,7 +45,7 @@ impl Solver for Solution {
 		Self { seeds, maps }
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		self.seeds
 			.iter()
 			.map(|&seed| {
@@ -77,11 +80,11 @@ impl Solver for Solution {
 			.unwrap()
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		let mut ranges = self
 			.seeds
-			.array_chunks()
-			.map(|&[start, length]| [start, start + length])
+			.chunks_exact(2)
+			.map(|chunk| [chunk[0], chunk[0] + chunk[1]])
 			.collect_vec();
 		let mut next_ranges = Vec::new();
 
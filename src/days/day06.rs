@@ -1,11 +1,12 @@
+#![cfg(feature = "day06")]
 #![allow(unused_mut)]
 #![allow(unused_variables)]
 #![allow(dead_code)]
 
 use crate::helpers::*;
 
-pub type A1 = impl Display + Debug + Clone;
-pub type A2 = impl Display + Debug + Clone;
+pub type A1 = u64;
+pub type A2 = u64;
 
 #[derive(Debug, Default, Clone)]
 pub struct Solution {
@@ -32,7 +33,7 @@ impl Solver for Solution {
 		Self { times, distances }
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		let mut mult = 1;
 		for (&time, &distance) in self.times.iter().zip(&self.distances) {
 			let mut winners = 0;
@@ -47,7 +48,7 @@ impl Solver for Solution {
 		mult
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		let time = self.times.iter().fold(0u64, |acc, &t| {
 			acc * 10u64.pow(t.length(10) as _) + t as u64
 		});
@@ -1,9 +1,11 @@
+#![cfg(feature = "day07")]
+
 use std::ops::Add;
 
 use crate::helpers::*;
 
-pub type A1 = impl Display + Debug + Clone;
-pub type A2 = impl Display + Debug + Clone;
+pub type A1 = u32;
+pub type A2 = u32;
 
 #[derive(Debug, Default, Clone)]
 pub struct Solution {
@@ -201,7 +203,7 @@ impl Solver for Solution {
 		Self { hands }
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		self.hands.sort_unstable();
 
 		self.hands
@@ -211,7 +213,7 @@ impl Solver for Solution {
 			.sum_self()
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		let mut joker_hands: Vec<JokerHand> = self.hands.iter().map(|&hand| hand.into()).collect();
 		joker_hands.sort_unstable();
 
@@ -1,7 +1,9 @@
+#![cfg(feature = "day08")]
+
 use crate::helpers::*;
 
-pub type A1 = impl Display + Debug + Clone;
-pub type A2 = impl Display + Debug + Clone;
+pub type A1 = isize;
+pub type A2 = usize;
 
 #[derive(Debug, Default, Clone)]
 pub struct Solution {
@@ -67,7 +69,7 @@ impl Solver for Solution {
 	type AnswerTwo = A2;
 
 	fn initialize(file: Vec<u8>, _: u8) -> Self {
-		let (turns_line, map_lines) = file.split_once(is(b'\n')).unwrap();
+		let (turns_line, map_lines) = file.split_once_by(is(b'\n')).unwrap();
 		let turns = turns_line
 			.iter()
 			.map(|&b| b.try_into().unwrap())
@@ -85,7 +87,7 @@ impl Solver for Solution {
 		Self { turns, map }
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		let start = Node::new(*b"AAA");
 		let end = Node::new(*b"ZZZ");
 
@@ -93,7 +95,7 @@ impl Solver for Solution {
 			.map_or(-1, |(n, _)| n as isize)
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		self.map
 			.keys()
 			.filter(|node| node.is_start())
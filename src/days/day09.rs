@@ -1,7 +1,9 @@
+#![cfg(feature = "day09")]
+
 use crate::helpers::*;
 
-pub type A1 = impl Display + Debug + Clone;
-pub type A2 = impl Display + Debug + Clone;
+pub type A1 = i64;
+pub type A2 = i64;
 
 #[derive(Debug, Default, Clone)]
 pub struct Solution {
@@ -21,7 +23,7 @@ impl Solver for Solution {
 		Self { histories }
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		self.histories
 			.iter()
 			.map(|history| {
@@ -31,8 +33,9 @@ impl Solver for Solution {
 					let new = intermediates
 						.last()
 						.unwrap()
-						.array_windows()
-						.map(|&[a, b]| {
+						.iter()
+						.tuple_windows()
+						.map(|(a, b)| {
 							let diff = b - a;
 							if diff != 0 {
 								all_zero = false;
@@ -58,7 +61,7 @@ impl Solver for Solution {
 			.sum_self()
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		self.histories
 			.iter()
 			.map(|history| {
@@ -68,8 +71,9 @@ impl Solver for Solution {
 					let new = intermediates
 						.last()
 						.unwrap()
-						.array_windows()
-						.map(|&[a, b]| {
+						.iter()
+						.tuple_windows()
+						.map(|(a, b)| {
 							let diff = b - a;
 							if diff != 0 {
 								all_zero = false;
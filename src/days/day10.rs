@@ -1,9 +1,11 @@
+#![cfg(feature = "day10")]
+
 use std::convert::identity;
 
 use crate::helpers::*;
 
-pub type A1 = impl Display + Debug + Clone;
-pub type A2 = impl Display + Debug + Clone;
+pub type A1 = usize;
+pub type A2 = usize;
 
 #[derive(Debug, Default, Clone)]
 pub struct Solution {
@@ -54,7 +56,7 @@ impl Solver for Solution {
 		}
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		let mut one = self.start;
 		let mut two = self.start;
 
@@ -67,47 +69,34 @@ impl Solver for Solution {
 
 		let (mut one_dir, mut two_dir) = 'start: {
 			let mut first = None;
-			match up {
-				Some(b'|' | b'7' | b'F') => {
-					if let Some(first) = first {
-						break 'start (first, Up);
-					} else {
-						first = Some(Up);
-					}
+			if let Some(b'|' | b'7' | b'F') = up {
+				if let Some(first) = first {
+					break 'start (first, Up);
+				} else {
+					first = Some(Up);
 				}
-				Some(_) | None => (),
 			}
-			match right {
-				Some(b'-' | b'7' | b'J') => {
-					if let Some(first) = first {
-						break 'start (first, Right);
-					} else {
-						first = Some(Right);
-					}
+			if let Some(b'-' | b'7' | b'J') = right {
+				if let Some(first) = first {
+					break 'start (first, Right);
+				} else {
+					first = Some(Right);
 				}
-				Some(_) | None => (),
 			}
-			match down {
-				Some(b'|' | b'L' | b'J') => {
-					if let Some(first) = first {
-						break 'start (first, Down);
-					} else {
-						first = Some(Down);
-					}
+			if let Some(b'|' | b'L' | b'J') = down {
+				if let Some(first) = first {
+					break 'start (first, Down);
+				} else {
+					first = Some(Down);
 				}
-				Some(_) | None => (),
 			}
-			match left {
-				Some(b'-' | b'L' | b'F') =>
-				{
-					#[allow(unused_assignments)]
-					if let Some(first) = first {
-						break 'start (first, Left);
-					} else {
-						first = Some(Left);
-					}
+			if let Some(b'-' | b'L' | b'F') = left {
+				#[allow(unused_assignments)]
+				if let Some(first) = first {
+					break 'start (first, Left);
+				} else {
+					first = Some(Left);
 				}
-				Some(_) | None => (),
 			}
 			unreachable!()
 		};
@@ -168,7 +157,7 @@ impl Solver for Solution {
 		unreachable!()
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		let mut total = 0;
 		for row in &self.map {
 			let mut inside_loop = false;
@@ -1,7 +1,9 @@
+#![cfg(feature = "day11")]
+
 use crate::helpers::*;
 
-pub type A1 = impl Display + Debug + Clone;
-pub type A2 = impl Display + Debug + Clone;
+pub type A1 = i64;
+pub type A2 = i64;
 
 #[derive(Debug, Default, Clone)]
 pub struct Solution {
@@ -20,11 +22,11 @@ impl Solver for Solution {
 		Self { file }
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		self.solve_with_expansion(2)
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		self.solve_with_expansion(1_000_000)
 	}
 
@@ -1,3 +1,5 @@
+#![cfg(feature = "day12")]
+
 use crate::helpers::*;
 
 pub type A1 = u64;
@@ -63,7 +65,7 @@ impl Solver for Solution {
 		let records = file
 			.lines()
 			.map(|line| {
-				let (row, groups) = line.split_once(is(b' ')).unwrap();
+				let (row, groups) = line.split_once_by(is(b' ')).unwrap();
 				let row = row.iter().cloned().map(Spring::from).collect();
 				let groups = groups.delimiter(',').multi_parse().unwrap();
 				Record { row, groups }
@@ -72,7 +74,7 @@ impl Solver for Solution {
 		Self { records }
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		let mut memo = Memo::new();
 		self.records
 			.iter()
@@ -80,10 +82,15 @@ impl Solver for Solution {
 			.sum()
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, ctx: &Context) -> Self::AnswerTwo {
+		ctx.progress(0, self.records.len() as u64);
 		self.records
 			.par_iter()
 			.map_with(Memo::new(), |memo, Record { row, groups }| {
+				if ctx.is_cancelled() {
+					return 0;
+				}
+
 				let mut new_row = Vec::with_capacity(row.len() * 5 + 4);
 				new_row.extend_from_slice(row);
 				for _ in 1..5 {
@@ -96,7 +103,9 @@ impl Solver for Solution {
 					new_groups.extend_from_slice(groups);
 				}
 
-				arrangements(&new_row, &new_groups, memo)
+				let count = arrangements(&new_row, &new_groups, memo);
+				ctx.add_progress(1);
+				count
 			})
 			.sum()
 	}
@@ -148,7 +157,7 @@ fn arrangements_inner(
 	let mut calculate = || {
 		// let (orig_row, orig_groups) = (row, groups);
 
-		let Some(&first) = row.take_first() else {
+		let Some(&first) = row.pop_first() else {
 			// There's definitely more groups, so this fails
 			return 0;
 		};
@@ -161,12 +170,12 @@ fn arrangements_inner(
 
 		if let Broken | Unknown = first {
 			let mut broken = || {
-				let Some(&group) = groups.take_first() else {
+				let Some(&group) = groups.pop_first() else {
 					// There are no more groups to match this broken spring.
 					return 0;
 				};
 				for _ in 1..group {
-					let Some(&next) = row.take_first() else {
+					let Some(&next) = row.pop_first() else {
 						// There are no more springs to fulfill this group.
 						return 0;
 					};
@@ -178,7 +187,7 @@ fn arrangements_inner(
 					}
 				}
 				// If this isn't the final, there needs to be one intact to break the group.
-				if let Some(&next) = row.take_first() {
+				if let Some(&next) = row.pop_first() {
 					if next == Broken {
 						return 0;
 					}
@@ -1,3 +1,5 @@
+#![cfg(feature = "day13")]
+
 use crate::helpers::*;
 
 pub type A1 = u32;
@@ -16,7 +18,7 @@ impl Solver for Solution {
 		Self { file }
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		self.solve(|grid| {
 			for split in 1..grid.len() {
 				let (before, after) = grid.split_at(split);
@@ -29,7 +31,7 @@ impl Solver for Solution {
 		})
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		self.solve(|grid| {
 			for split in 1..grid.len() {
 				let (before, after) = grid.split_at(split);
@@ -90,15 +92,15 @@ impl Solution {
 				horiz_len += 1;
 			}
 
-			bytes.take(..horiz_len + 1).unwrap();
+			bytes.pop_prefix(horiz_len + 1).unwrap();
 
 			let mut vert_len = 1;
 			for (mut chunk, horiz) in bytes.chunks(horiz_len + 1).zip(&mut grid_horizontal[1..]) {
 				if chunk[0] == EOL {
 					break;
 				}
-				bytes.take(..chunk.len()).unwrap();
-				chunk.take_last();
+				bytes.pop_prefix(chunk.len()).unwrap();
+				chunk.pop_last();
 				for (&byte, vert) in chunk.iter().zip(&mut grid_vertical) {
 					let bit = (byte & 1) as u32;
 
@@ -119,7 +121,7 @@ impl Solution {
 				horiz_ans * 100
 			};
 
-			if bytes.take_first().is_none() {
+			if bytes.pop_first().is_none() {
 				break;
 			}
 		}
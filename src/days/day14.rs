@@ -1,11 +1,13 @@
+#![cfg(feature = "day14")]
+
 use std::cell::Cell;
 use std::fmt::Write;
 use std::hash::Hasher;
 
 use crate::helpers::*;
 
-pub type A1 = impl Display + Debug + Clone;
-pub type A2 = impl Display + Debug + Clone;
+pub type A1 = u32;
+pub type A2 = u32;
 
 #[derive(Debug, Default, Clone)]
 pub struct Solution {
@@ -25,12 +27,12 @@ impl Solver for Solution {
 		this
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		self.tilt_north();
 		self.weight()
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		for _ in 0..PARTIAL {
 			self.cycle();
 		}
@@ -1,3 +1,5 @@
+#![cfg(feature = "day15")]
+
 use crate::helpers::*;
 
 pub type A1 = u64;
@@ -16,7 +18,7 @@ impl Solver for Solution {
 		Self { file }
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		let mut total = 0;
 		let mut current_hash = 0;
 		for &b in &self.file[..self.file.len() - 1] {
@@ -31,7 +33,7 @@ impl Solver for Solution {
 		total
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		let mut boxes = std::array::from_fn(|_| LensBox::default());
 		let mut input = self.file.trim_ascii_end();
 		while !input.is_empty() {
@@ -72,7 +74,7 @@ fn lens_operation(step_and_rest: &mut &[u8], boxes: &mut [LensBox; 256]) {
 	let mut operation = 0;
 	let mut hash = 0;
 	for c in &mut label {
-		let byte = *step_and_rest.take_first().unwrap();
+		let byte = *step_and_rest.pop_first().unwrap();
 		if byte & 0b01000000 != 0 {
 			*c = byte;
 			hash_one(&mut hash, byte);
@@ -83,7 +85,7 @@ fn lens_operation(step_and_rest: &mut &[u8], boxes: &mut [LensBox; 256]) {
 	}
 
 	if operation == b'=' {
-		let focal_length = *step_and_rest.take_first().unwrap() - b'0';
+		let focal_length = *step_and_rest.pop_first().unwrap() - b'0';
 
 		let b = &mut boxes[hash as usize];
 		let mut replaced = false;
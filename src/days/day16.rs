@@ -1,9 +1,11 @@
+#![cfg(feature = "day16")]
+
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 use crate::helpers::*;
 
-pub type A1 = impl Display + Debug + Clone;
-pub type A2 = impl Display + Debug + Clone;
+pub type A1 = usize;
+pub type A2 = usize;
 
 #[derive(Debug, Default, Clone)]
 pub struct Solution {
@@ -20,13 +22,13 @@ impl Solver for Solution {
 		Self { map: file, row_len }
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		let start = ([0, 0], [0, 1]);
 		let (mut tiles, mut beams) = self.initialize_allocs();
 		self.propogate_laser(start, &mut tiles, &mut beams)
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		let len = self.row_len as Coord;
 
 		(0..len)
@@ -1,7 +1,9 @@
+#![cfg(feature = "day17")]
+
 use crate::helpers::*;
 
-pub type A1 = impl Display + Debug + Clone;
-pub type A2 = impl Display + Debug + Clone;
+pub type A1 = u64;
+pub type A2 = u64;
 
 #[derive(Debug, Default, Clone)]
 pub struct Solution {
@@ -24,7 +26,7 @@ impl Solver for Solution {
 		}
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		let start = [0, 0];
 		let mut queue = MinHeap::from_iter([Position {
 			heat: 0,
@@ -62,7 +64,7 @@ impl Solver for Solution {
 		panic!("no path found p1")
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		let start = [0, 0];
 		let mut queue = MinHeap::from_iter([
 			Position {
@@ -163,9 +165,7 @@ impl Solution {
 			straights,
 		} = position;
 
-		let Some(new_straights) = straights.checked_sub(1) else {
-			return None;
-		};
+		let new_straights = straights.checked_sub(1)?;
 
 		let new_coords = add(coords, direction);
 		let add_heat = self.get(new_coords)?;
@@ -1,7 +1,9 @@
+#![cfg(feature = "day18")]
+
 use crate::helpers::*;
 
-pub type A1 = impl Display + Debug + Clone;
-pub type A2 = impl Display + Debug + Clone;
+pub type A1 = i64;
+pub type A2 = i64;
 
 #[derive(Debug, Default, Clone)]
 pub struct Solution {
@@ -16,11 +18,11 @@ impl Solver for Solution {
 		Self { file }
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		Step::area(self.file.lines().map(|line| Step::try_from(line).unwrap()))
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		Step::area(
 			self.file
 				.lines()
@@ -51,7 +53,7 @@ struct Step {
 
 impl Step {
 	fn alt_try_from(value: &[u8]) -> Result<Step, ()> {
-		let (&[dist_hex @ .., f, _], _) = value.split_last_chunk::<7>().unwrap();
+		let (_, &[dist_hex @ .., f, _]) = value.split_last_chunk::<7>().unwrap();
 		let direction = Direction::from_number(f - b'0').unwrap();
 
 		let mut distance = 0;
@@ -89,7 +91,7 @@ impl TryFrom<&[u8]> for Step {
 
 	fn try_from(value: &[u8]) -> Result<Step, ()> {
 		let direction = value[0].try_into()?;
-		let (d, _c) = value[2..].split_once(is(b' ')).ok_or(())?;
+		let (d, _c) = value[2..].split_once_by(is(b' ')).ok_or(())?;
 		let distance = d.parse().ok_or(())?;
 		// let color = c[2..][..6].try_into()?;
 		Ok(Self {
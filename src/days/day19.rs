@@ -1,3 +1,5 @@
+#![cfg(feature = "day19")]
+
 use std::collections::hash_map::Entry;
 use std::ops::{Index, IndexMut, Range};
 
@@ -24,14 +26,14 @@ impl Solver for Solution {
 		WorkflowParser::default().parse(file).unwrap()
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		parse_parts(&self.file[self.offset..])
 			.filter(|&part| self.part_is_accepted(part))
 			.map(|part| part.0.into_iter().map(|d| d as u64).sum::<u64>())
 			.sum()
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		let all_possible = PartRange::new(1..4001);
 		let mut stack = Vec::with_capacity(15);
 		stack.push((self.start, all_possible));
@@ -67,59 +69,60 @@ impl Solver for Solution {
 }
 
 fn parse_parts(mut lines: &[u8]) -> impl Iterator<Item = Part> + '_ {
-	lines.take_last().unwrap();
-	std::iter::from_coroutine(move || {
-		let start = lines.take(..3).unwrap();
-		debug_assert_eq!(DisplaySlice(start), DisplaySlice(b"{x=".as_slice()));
-		loop {
-			let mut x = 0;
-			loop {
-				let &d = lines.take_first().unwrap();
-				if d == b',' {
-					break;
-				}
-				x *= 10;
-				x += (d - b'0') as Number;
-			}
+	lines.pop_last().unwrap();
+	let start = lines.pop_prefix(3).unwrap();
+	debug_assert_eq!(DisplaySlice(start), DisplaySlice(b"{x=".as_slice()));
+	let mut done = false;
+	std::iter::from_fn(move || {
+		if done {
+			return None;
+		}
 
-			lines.take(..2).unwrap();
-			let mut m = 0;
-			loop {
-				let &d = lines.take_first().unwrap();
-				if d == b',' {
-					break;
-				}
-				m *= 10;
-				m += (d - b'0') as Number;
+		let mut x = 0;
+		loop {
+			let &d = lines.pop_first().unwrap();
+			if d == b',' {
+				break;
 			}
+			x *= 10;
+			x += (d - b'0') as Number;
+		}
 
-			lines.take(..2).unwrap();
-			let mut a = 0;
-			loop {
-				let &d = lines.take_first().unwrap();
-				if d == b',' {
-					break;
-				}
-				a *= 10;
-				a += (d - b'0') as Number;
+		lines.pop_prefix(2).unwrap();
+		let mut m = 0;
+		loop {
+			let &d = lines.pop_first().unwrap();
+			if d == b',' {
+				break;
 			}
+			m *= 10;
+			m += (d - b'0') as Number;
+		}
 
-			lines.take(..2).unwrap();
-			let mut s = 0;
-			loop {
-				let &d = lines.take_first().unwrap();
-				if d == b'}' {
-					break;
-				}
-				s *= 10;
-				s += (d - b'0') as Number;
+		lines.pop_prefix(2).unwrap();
+		let mut a = 0;
+		loop {
+			let &d = lines.pop_first().unwrap();
+			if d == b',' {
+				break;
 			}
+			a *= 10;
+			a += (d - b'0') as Number;
+		}
 
-			yield Part::new(x, m, a, s);
-			if lines.take(..4).is_none() {
+		lines.pop_prefix(2).unwrap();
+		let mut s = 0;
+		loop {
+			let &d = lines.pop_first().unwrap();
+			if d == b'}' {
 				break;
 			}
+			s *= 10;
+			s += (d - b'0') as Number;
 		}
+
+		done = lines.pop_prefix(4).is_none();
+		Some(Part::new(x, m, a, s))
 	})
 }
 
@@ -392,18 +395,18 @@ impl WorkflowParser {
 		let mut state = WorkflowState::default();
 
 		loop {
-			let &b = slice.take_first()?;
+			let &b = slice.pop_first()?;
 
 			state = match state {
 				WorkflowState::Name => {
 					if b == b'\n' {
 						break;
 					}
-					let &b2 = slice.take_first()?;
+					let &b2 = slice.pop_first()?;
 					let mut name = [b, b2, 0];
-					let &b3 = slice.take_first()?;
+					let &b3 = slice.pop_first()?;
 					if b3 != b'{' {
-						let &bracket = slice.take_first()?;
+						let &bracket = slice.pop_first()?;
 						debug_assert_eq!(bracket, b'{');
 						name[2] = b3;
 					}
@@ -414,7 +417,7 @@ impl WorkflowParser {
 				}
 
 				WorkflowState::CategoryOrFallback { name, mut workflow } => {
-					let &b2 = slice.take_first()?;
+					let &b2 = slice.pop_first()?;
 					if let Some(operation) = Operation::new(b2) {
 						let category = Category::new(b)?;
 						WorkflowState::Number {
@@ -430,18 +433,18 @@ impl WorkflowParser {
 								&mut self.name_map,
 								&mut self.workflows,
 							);
-							slice.take_first()?;
+							slice.pop_first()?;
 						} else {
-							let &b3 = slice.take_first()?;
+							let &b3 = slice.pop_first()?;
 							let fallback = if b3 == b'}' {
 								[b, b2, 0]
 							} else {
-								let &bracket = slice.take_first()?;
+								let &bracket = slice.pop_first()?;
 								debug_assert_eq!(bracket, b'}');
 								[b, b2, b3]
 							};
 
-							let &newline = slice.take_first()?;
+							let &newline = slice.pop_first()?;
 							debug_assert_eq!(newline, b'\n');
 							workflow.fallback = Name::add_to_name_map(
 								fallback,
@@ -463,7 +466,7 @@ impl WorkflowParser {
 				} => {
 					let mut number = (b - b'0') as Number;
 					loop {
-						let &b = slice.take_first()?;
+						let &b = slice.pop_first()?;
 						if b == b':' {
 							break;
 						}
@@ -471,17 +474,17 @@ impl WorkflowParser {
 						number += (b - b'0') as Number;
 					}
 
-					let Some(&[b1, b2]) = slice.take(..2) else {
+					let Some(&[b1, b2]) = slice.pop_prefix(2) else {
 						panic!()
 					};
 					let destination = if b2 == b',' {
 						[b1, 0, 0]
 					} else {
-						let &b3 = slice.take_first()?;
+						let &b3 = slice.pop_first()?;
 						if b3 == b',' {
 							[b1, b2, 0]
 						} else {
-							let &comma = slice.take_first()?;
+							let &comma = slice.pop_first()?;
 							debug_assert_eq!(comma, b',');
 							[b1, b2, b3]
 						}
@@ -547,6 +550,7 @@ enum WorkflowState {
 	},
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for WorkflowState {
 	fn default() -> Self {
 		Self::Name
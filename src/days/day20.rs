@@ -1,3 +1,5 @@
+#![cfg(feature = "day20")]
+
 use std::fmt::Write;
 
 use arrayvec::ArrayVec;
@@ -26,7 +28,7 @@ impl Solver for Solution {
 		}
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		let mut low = 0;
 		let mut high = 0;
 		for _ in 0..1000 {
@@ -38,7 +40,7 @@ impl Solver for Solution {
 		low * high
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		if self.p1 {
 			self.configuration.reset();
 		}
@@ -251,7 +253,7 @@ impl Module {
 	}
 
 	fn from_line(line: &[u8]) -> Option<Self> {
-		let (before, after) = line.split_once(is(b' '))?;
+		let (before, after) = line.split_once_by(is(b' '))?;
 		let after = &after[3..];
 		let outputs = after.delimiter(", ").map(Name::from_slice).collect();
 		match before {
@@ -274,15 +276,11 @@ impl Module {
 		pulse: Pulse,
 		to: Name,
 	) -> impl IntoIterator<Item = (Name, Pulse, Name)> + '_ {
-		gen_iter(move || {
-			let Some(pulse) = self.mod_type.receive(from, pulse) else {
-				return;
-			};
-
-			for &out in &self.outputs {
-				yield (to, pulse, out)
-			}
-		})
+		let pulse = self.mod_type.receive(from, pulse);
+		let outputs = &self.outputs;
+		pulse
+			.into_iter()
+			.flat_map(move |pulse| outputs.iter().map(move |&out| (to, pulse, out)))
 	}
 
 	fn reset(&mut self) {
@@ -1,3 +1,5 @@
+#![cfg(feature = "day21")]
+
 use crate::helpers::*;
 
 pub type A1 = usize;
@@ -16,7 +18,7 @@ impl Solver for Solution {
 		Self { file }
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		let mut grid = self.file.grid(identity);
 		let size = grid.len() as isize;
 		let start = [size / 2, size / 2];
@@ -24,7 +26,7 @@ impl Solver for Solution {
 		PlotExplorer::new(start, &grid).run(64)
 	}
 
-	fn part_two(&mut self, d: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, d: u8, _: &Context) -> Self::AnswerTwo {
 		let mut grid = self.file.grid(identity);
 
 		let middle = (grid.len() as isize - 1) / 2;
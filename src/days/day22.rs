@@ -1,3 +1,5 @@
+#![cfg(feature = "day22")]
+
 use crate::helpers::*;
 
 pub type A1 = usize;
@@ -16,7 +18,7 @@ impl Solver for Solution {
 	fn initialize(file: Vec<u8>, _: u8) -> Self {
 		let mut bricks = Vec::default();
 		for line in file.lines() {
-			let (a, b) = line.split_once(is(b'~')).unwrap();
+			let (a, b) = line.split_once_by(is(b'~')).unwrap();
 			let a = a
 				.delimiter(',')
 				.map(|n| n.parse().unwrap())
@@ -58,7 +60,7 @@ impl Solver for Solution {
 		this
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		let mut safe = HashSet::from_iter(0..self.bricks.len());
 
 		for brick in &self.bricks {
@@ -100,7 +102,7 @@ impl Solver for Solution {
 		safe.len()
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		(0..self.bricks.len())
 			.map(|i| {
 				let mut other = self.clone();
@@ -182,39 +184,30 @@ struct Brick {
 
 impl Brick {
 	fn cubes(self) -> impl Iterator<Item = Point> {
-		gen_iter(move || {
-			if self.start.z != self.end.z {
-				for z in self.start.z..self.end.z + 1 {
-					yield Point {
-						z,
-						y: self.start.y,
-						x: self.start.x,
-					}
-				}
-			} else if self.start.y != self.end.y {
-				for y in self.start.y..self.end.y + 1 {
-					yield Point {
-						z: self.start.z,
-						y,
-						x: self.start.x,
-					}
-				}
-			} else if self.start.x != self.end.x {
-				for x in self.start.x..self.end.x + 1 {
-					yield Point {
-						z: self.start.z,
-						y: self.start.y,
-						x,
-					}
-				}
+		let Self { start, end } = self;
+		let mut next = Some(start);
+		std::iter::from_fn(move || {
+			let point = next?;
+			next = if start.z != end.z {
+				(point.z < end.z).then_some(Point {
+					z: point.z + 1,
+					..point
+				})
+			} else if start.y != end.y {
+				(point.y < end.y).then_some(Point {
+					y: point.y + 1,
+					..point
+				})
+			} else if start.x != end.x {
+				(point.x < end.x).then_some(Point {
+					x: point.x + 1,
+					..point
+				})
 			} else {
 				// One cube brick
-				yield Point {
-					z: self.start.z,
-					y: self.start.y,
-					x: self.start.x,
-				}
-			}
+				None
+			};
+			Some(point)
 		})
 	}
 }
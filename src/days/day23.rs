@@ -1,3 +1,5 @@
+#![cfg(feature = "day23")]
+
 use std::collections::hash_map::Entry;
 use std::ops::{Add, AddAssign, Index, IndexMut};
 
@@ -103,7 +105,7 @@ impl Solver for Solution {
 		}
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		let mut stack = ArrayVec::<_, 50>::new();
 		stack.push((0, 0, Down));
 
@@ -159,7 +161,7 @@ impl Solver for Solution {
 		max + self.end.1
 	}
 
-	fn part_two(&mut self, d: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, d: u8, ctx: &Context) -> Self::AnswerTwo {
 		let mut stack = ArrayVec::<_, 50>::new();
 		stack.push((0, 0, Down));
 
@@ -171,6 +173,7 @@ impl Solver for Solution {
 
 		let mut max_nodes = 0;
 		let mut max = 0;
+		let mut iterations = 0u32;
 		while let Some((mut node_index, mut distance, mut direction)) = stack.pop() {
 			visited[node_index] = false;
 			nodes_remaining += 1;
@@ -178,6 +181,14 @@ impl Solver for Solution {
 				continue;
 			}
 
+			iterations = iterations.wrapping_add(1);
+			if iterations % PROGRESS_INTERVAL == 0 {
+				if ctx.is_cancelled() {
+					break;
+				}
+				report_progress(ctx, &stack);
+			}
+
 			loop {
 				let (next_index, next_distance) = self.graph[node_index][direction];
 				if (next_index == BLOCKED) || visited[next_index]
@@ -240,6 +251,30 @@ type Coord = i16;
 type Node = [(usize, i64); 4];
 const BLOCKED: usize = usize::MAX;
 
+/// How many DFS steps to take between progress reports.
+const PROGRESS_INTERVAL: u32 = 1 << 16;
+/// How many levels of the DFS stack are used to estimate progress.
+const PROGRESS_DEPTH: usize = 8;
+
+/// Estimates how much of the search tree has been explored from the directions left to try on
+/// the first few levels of the stack. Directions are tried in the order left, up, right, down,
+/// and each stack entry holds the next direction to try.
+fn report_progress(ctx: &Context, stack: &[(usize, i64, Direction)]) {
+	let mut done = 0;
+	let mut scale = 4u64.pow(PROGRESS_DEPTH as u32);
+	for &(_, _, direction) in stack.iter().take(PROGRESS_DEPTH) {
+		scale /= 4;
+		let tried = match direction {
+			Left => 4,
+			Up => 1,
+			Right => 2,
+			Down => 3,
+		};
+		done += (tried - 1) * scale;
+	}
+	ctx.progress(done, 4u64.pow(PROGRESS_DEPTH as u32));
+}
+
 impl Solution {}
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash)]
@@ -1,6 +1,12 @@
+#![cfg(feature = "day24")]
+
+#[cfg(feature = "z3")]
 use itertools::izip;
+use num_rational::BigRational;
+#[cfg(feature = "z3")]
 use z3::ast::{Ast, Int};
-use z3::{Config, Context};
+#[cfg(feature = "z3")]
+use z3::{Config, Context as Z3Context};
 
 use crate::helpers::*;
 
@@ -21,7 +27,7 @@ impl Solver for Solution {
 		Self { hailstones }
 	}
 
-	fn part_one(&mut self, d: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, d: u8, _: &Context) -> Self::AnswerOne {
 		let (min, max) = match d {
 			0 => (MIN, MAX),
 			1 => (7.0, 27.0),
@@ -71,8 +77,44 @@ impl Solver for Solution {
 		intersects
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
-		let c = Context::new(&Config::new());
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
+		let stones = self.hailstones.iter().map(|h| h.to_stone()).collect_vec();
+		let (pos, _) = stones
+			.iter()
+			.tuple_windows()
+			.find_map(|(a, b, c)| {
+				let rock = throw([a, b, c])?;
+				stones
+					.iter()
+					.all(|&stone| hits(rock, stone))
+					.then_some(rock)
+			})
+			.expect("no throw hits every hailstone");
+
+		#[cfg(feature = "z3")]
+		assert_eq!(pos, self.throw_z3(), "z3 found a different throw");
+
+		pos.into_iter().sum()
+	}
+
+	fn run_any<W: std::fmt::Write>(
+		&mut self,
+		part: u32,
+		_writer: W,
+		_: u8,
+	) -> Res<std::time::Duration> {
+		#[allow(clippy::match_single_binding)]
+		match part {
+			_ => Err(AocError::PartNotFound),
+		}
+	}
+}
+
+impl Solution {
+	/// Finds the starting position of the rock with z3, using the first five hailstones.
+	#[cfg(feature = "z3")]
+	fn throw_z3(&self) -> [i64; 3] {
+		let c = Z3Context::new(&Config::new());
 
 		let ps = ["pz", "py", "px"].map(|p| Int::new_const(&c, p));
 		let vs = ["vz", "vy", "vx"].map(|v| Int::new_const(&c, v));
@@ -80,8 +122,7 @@ impl Solver for Solution {
 
 		let hail: [_; 5] = self
 			.hailstones
-			.array_chunks()
-			.next()
+			.first_chunk::<5>()
 			.unwrap()
 			.map(|h| [h.pos, h.vel].map(|pv| pv.map(|n| Int::from_i64(&c, n as _))));
 
@@ -94,22 +135,100 @@ impl Solver for Solution {
 
 		assert_eq!(solver.check(), z3::SatResult::Sat);
 		let model = solver.get_model().unwrap();
-		let ps_result = ps.map(|p| model.eval(&p, true).unwrap().as_i64().unwrap());
+		ps.map(|p| model.eval(&p, true).unwrap().as_i64().unwrap())
+	}
+}
 
-		ps_result.into_iter().sum()
+/// Position and velocity, in the same order as [`Hailstone`].
+type Stone = ([i64; 3], [i64; 3]);
+
+/// Finds the rock throw that hits all three `stones`.
+///
+/// Hitting stone `i` means `(P - p_i) × (V - v_i) = 0`. Expanding that, the `P × V` term is the
+/// same for every stone, so subtracting two stones' equations leaves
+/// `P × (v_i - v_j) + (p_i - p_j) × V = p_i × v_i - p_j × v_j`, which is linear. Two pairs of stones
+/// give six equations for the six unknowns. The coefficients are products of positions, so this
+/// needs [`BigRational`]; `Rational64` overflows during elimination on real inputs.
+///
+/// Returns `None` if the stones don't pin down a single throw with integer coordinates.
+fn throw(stones: [&Stone; 3]) -> Option<Stone> {
+	let [(p0, v0), rest @ ..] = stones.map(|&(p, v)| (p.map(i128::from), v.map(i128::from)));
+	let mut matrix = Vec::with_capacity(6);
+	for (p, v) in rest {
+		let dp = sub(p0, p);
+		let dv = sub(v0, v);
+		let rhs = sub(cross(p0, v0), cross(p, v));
+		matrix.extend([
+			[0, dv[2], -dv[1], 0, -dp[2], dp[1], rhs[0]],
+			[-dv[2], 0, dv[0], dp[2], 0, -dp[0], rhs[1]],
+			[dv[1], -dv[0], 0, -dp[1], dp[0], 0, rhs[2]],
+		]);
 	}
+	let matrix = matrix
+		.into_iter()
+		.map(|row| row.map(|n| BigRational::from_integer(n.into())))
+		.collect();
 
-	fn run_any<W: std::fmt::Write>(
-		&mut self,
-		part: u32,
-		_writer: W,
-		_: u8,
-	) -> Res<std::time::Duration> {
-		#[allow(clippy::match_single_binding)]
-		match part {
-			_ => Err(AocError::PartNotFound),
+	let solution = gauss_jordan(matrix)?
+		.into_iter()
+		.map(|n| n.is_integer().then(|| n.to_integer()))
+		.map(|n| i64::try_from(n?).ok())
+		.collect::<Option<Vec<_>>>()?;
+	let (pos, vel) = solution.split_at(3);
+	Some((pos.try_into().unwrap(), vel.try_into().unwrap()))
+}
+
+/// Solves the augmented `6 × 7` system in `matrix`. Returns `None` if it is singular.
+fn gauss_jordan(mut matrix: Vec<[BigRational; 7]>) -> Option<Vec<BigRational>> {
+	let zero = BigRational::default();
+	for col in 0..matrix.len() {
+		let pivot = (col..matrix.len()).find(|&row| matrix[row][col] != zero)?;
+		matrix.swap(col, pivot);
+		let pivot_row = matrix[col].clone();
+		for (row, values) in matrix.iter_mut().enumerate() {
+			if row == col || values[col] == zero {
+				continue;
+			}
+			let factor = &values[col] / &pivot_row[col];
+			for (value, p) in values.iter_mut().zip(&pivot_row) {
+				*value -= &factor * p;
+			}
 		}
 	}
+	Some(
+		matrix
+			.iter()
+			.enumerate()
+			.map(|(i, row)| &row[6] / &row[i])
+			.collect(),
+	)
+}
+
+/// Checks that `rock` hits `stone` at a whole, non-negative time.
+fn hits((rock_pos, rock_vel): Stone, (pos, vel): Stone) -> bool {
+	let widen = |a: [i64; 3]| a.map(i128::from);
+	// Where the stone starts relative to the rock, and how fast the rock closes that gap
+	let gap = sub(widen(pos), widen(rock_pos));
+	let closing = sub(widen(rock_vel), widen(vel));
+	if cross(gap, closing) != [0; 3] {
+		return false;
+	}
+	match closing.iter().position(|&n| n != 0) {
+		Some(i) => gap[i] % closing[i] == 0 && gap[i] / closing[i] >= 0,
+		None => gap == [0; 3],
+	}
+}
+
+fn sub(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+	[a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+	[
+		a[1] * b[2] - a[2] * b[1],
+		a[2] * b[0] - a[0] * b[2],
+		a[0] * b[1] - a[1] * b[0],
+	]
 }
 
 type Unit = f64;
@@ -124,7 +243,7 @@ struct Hailstone {
 
 impl Hailstone {
 	fn from_line(line: &[u8]) -> Self {
-		let (before, after) = line.split_once(is(b'@')).unwrap();
+		let (before, after) = line.split_once_by(is(b'@')).unwrap();
 		let pos = before
 			.trim_ascii()
 			.delimiter(", ")
@@ -140,4 +259,10 @@ impl Hailstone {
 			.unwrap();
 		Self { pos, vel }
 	}
+
+	/// The hailstone with exact coordinates. Inputs only have integers, and all of them fit in the
+	/// 53 bits that `f64` holds exactly.
+	fn to_stone(self) -> Stone {
+		(self.pos.map(|n| n as i64), self.vel.map(|n| n as i64))
+	}
 }
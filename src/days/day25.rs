@@ -1,3 +1,5 @@
+#![cfg(feature = "day25")]
+
 use std::collections::hash_map::Entry;
 
 use arrayvec::ArrayVec;
@@ -5,7 +7,7 @@ use arrayvec::ArrayVec;
 use crate::helpers::*;
 
 pub type A1 = usize;
-pub type A2 = impl Display + Debug + Clone;
+pub type A2 = &'static str;
 
 #[derive(Debug, Default, Clone)]
 pub struct Solution {
@@ -20,10 +22,10 @@ impl Solver for Solution {
 		Self { file }
 	}
 
-	fn part_one(&mut self, _: u8) -> Self::AnswerOne {
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
 		let mut components: HashMap<&[u8], HashSet<&[u8]>> = HashMap::new();
 		for line in self.file.lines() {
-			let (name, rest) = line.split_once(is(b':')).unwrap();
+			let (name, rest) = line.split_once_by(is(b':')).unwrap();
 			let rest: ArrayVec<_, 10> = rest.trim_ascii().delimiter(' ').collect();
 			components.entry(name).or_default().extend(rest.clone());
 
@@ -54,7 +56,7 @@ impl Solver for Solution {
 		one_side.len() * components.len()
 	}
 
-	fn part_two(&mut self, _: u8) -> Self::AnswerTwo {
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
 		"It's snowing ❄️"
 	}
 
@@ -88,13 +90,16 @@ fn find_longest_path<'a>(
 			queue.push_back(connection);
 		}
 	}
-	gen_iter(move || loop {
+	let mut done = false;
+	std::iter::from_fn(move || {
+		if done {
+			return None;
+		}
 		let next = visited[&end];
-		yield (end, next);
+		let edge = (end, next);
 		end = next;
-		if end == start {
-			break;
-		}
+		done = end == start;
+		Some(edge)
 	})
 }
 
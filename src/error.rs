@@ -25,7 +25,7 @@ pub enum AocError {
 	#[error(transparent)]
 	OtherError {
 		#[from]
-		source: Box<dyn std::error::Error>,
+		source: Box<dyn std::error::Error + Send + Sync>,
 	},
 	#[error("Couldn't fetch input from network. Status: {status}\nContent:\n{response}")]
 	InputResponse { status: u16, response: String },
@@ -44,6 +44,8 @@ pub enum AocError {
 	},
 	#[error("Day {0} not found")]
 	DayNotFound(u32),
+	#[error("Day {0} is compiled out, build with `--features day{0:02}` to run it")]
+	DayCompiledOut(u32),
 	#[error("Argument was empty")]
 	EmptyArgument,
 	#[error("Part was empty in {arg}")]
@@ -54,6 +56,58 @@ pub enum AocError {
 	IncorrectAnswer,
 	#[error("{0} answers were incorrect.")]
 	MultipleIncorrect(u32),
+	#[error("Interrupted")]
+	Interrupted,
+	#[error("{0} parts gave different answers across runs.")]
+	Nondeterministic(u32),
+	#[error("Couldn't read line `{line}` of baseline report {path}")]
+	BadBaseline { path: String, line: String },
+	#[error("Day {0} already has a solution module")]
+	DayExists(u32),
+	#[error("Couldn't find where to register day {day} in {file}")]
+	CannotRegister { day: u32, file: &'static str },
+	#[error("Couldn't fetch stats from network. Status {status}, content:\n{response}")]
+	StatsResponse { status: u16, response: String },
+	#[error("The stats page has no days with stars, leaving the README as is")]
+	EmptyStats,
+	#[error(
+		"README is missing the `<!-- leaderboard start -->` and `<!-- leaderboard end -->` markers"
+	)]
+	MissingMarkers,
+	#[error("Couldn't fetch leaderboard from network. Status {status}, content:\n{response}")]
+	LeaderboardResponse { status: u16, response: String },
+	#[error("No leaderboard ID specified")]
+	NoLeaderboardId,
+	#[error("Leaderboard ID {arg:?} isn't a number")]
+	InvalidLeaderboardId { arg: String },
+	#[error("Every day has already been released")]
+	AllReleased,
+	#[error("`--input` and `--answers` can only be used with one day selected")]
+	ExternalFileForManyDays,
+	#[error("Save mode doesn't write to `--answers` files, validate against them instead")]
+	SaveToExternalAnswers,
+	#[error("Bisect mode needs exactly one day and part, like `3.2`")]
+	BisectTarget,
+	#[error("Bisect mode needs a known good commit, passed with `--good`")]
+	NoGoodCommit,
+	#[error("The work tree has uncommitted changes, commit or stash them before bisecting")]
+	DirtyWorkTree,
+	#[error("`git {args}` failed:\n{stderr}")]
+	Git { args: String, stderr: String },
+	#[error("Couldn't build or run the runner at {0}")]
+	CannotMeasure(String),
+	#[error("The good commit {0} is already slower than `--slower-than`")]
+	GoodCommitIsBad(String),
+	#[error("No external solver called {0} in external_solvers.json")]
+	UnknownSolver(String),
+	#[error("External solver {name} failed: {reason}")]
+	ExternalSolver { name: String, reason: String },
+	#[error("{0} parts gave different answers across solvers.")]
+	SolversDisagree(u32),
+	#[error("Couldn't load hot day library {path}: {reason}")]
+	HotLoad { path: String, reason: String },
+	#[error("{0}")]
+	HotPart(String),
 }
 
 impl From<ureq::Error> for AocError {
@@ -0,0 +1,100 @@
+//! Running solutions from other programs. Nothing here prints or touches the filesystem: inputs
+//! are passed in, and answers, timings, and errors are returned as values.
+//!
+//! ```
+//! let input = b"1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
+//! assert_eq!(aoc2023::solve(1, 1, input).unwrap(), aoc2023::Answer::Integer(142));
+//! assert!(matches!(aoc2023::solve(26, 1, input), Err(aoc2023::AocError::DayNotFound(26))));
+//!
+//! let timed = aoc2023::facade::solve_timed(1, 2, input, &Default::default()).unwrap();
+//! println!("{} in {:?}", timed.answer, timed.init_time + timed.time);
+//! ```
+
+use std::time::Duration;
+
+use crate::normalize::{normalize, Normalization};
+#[cfg(any_day)]
+use crate::runner::time_fn;
+use crate::solution::SolverSafe;
+#[cfg(any_day)]
+use crate::Solver;
+use crate::{days, Answer, AocError, Context, Res};
+
+/// An answer and how long it took to get.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timed {
+	pub answer: Answer,
+	/// Time spent in [`Solver::initialize`](crate::Solver::initialize).
+	pub init_time: Duration,
+	/// Time spent in the part itself.
+	pub time: Duration,
+}
+
+/// Solves `part` of `day` for `input`.
+///
+/// The input gets the runner's default [normalization](crate::normalize), so it doesn't need a
+/// trailing newline.
+pub fn solve(day: u32, part: u32, input: &[u8]) -> Res<Answer> {
+	solve_timed(day, part, input, &Context::new()).map(|timed| timed.answer)
+}
+
+/// Like [`solve`], but also returns timings. Long-running parts report progress to `ctx`, and
+/// stop early if it is cancelled from another thread.
+pub fn solve_timed(day: u32, part: u32, input: &[u8], ctx: &Context) -> Res<Timed> {
+	let mut input = input.to_vec();
+	normalize(&mut input, &Normalization::DEFAULT);
+	let (init_time, mut solver) = initialize(day, input, 0)?;
+	let (time, answer) = run_part(&mut *solver, part, 0, ctx)?;
+	Ok(Timed {
+		answer,
+		init_time,
+		time,
+	})
+}
+
+/// Returns every day that is compiled in, in order.
+pub fn days() -> impl Iterator<Item = u32> {
+	(1..=25).filter(|&day| days::is_compiled(day))
+}
+
+/// Creates the solver for `day` from `input`, which is used as is. Returns the time
+/// [`Solver::initialize`](crate::Solver::initialize) took with it.
+#[allow(clippy::zero_prefixed_literal)]
+#[cfg_attr(
+	not(any_day),
+	allow(unused_imports, unused_variables, unreachable_code)
+)]
+pub fn initialize(day: u32, input: Vec<u8>, dbg: u8) -> Res<(Duration, Box<dyn SolverSafe>)> {
+	use crate::days::*;
+	macro_rules! solver_match {
+		($($num:literal => $day:ident if $feature:literal,)*) => {
+			match day {
+				$(
+					#[cfg(feature = $feature)]
+					$num => {
+						let (time, solver) = time_fn(|| $day::Solution::initialize(input, dbg));
+						(time, Box::new(solver))
+					}
+					#[cfg(not(feature = $feature))]
+					$num => return Err(AocError::DayCompiledOut($num)),
+				)*
+				d => return Err(AocError::DayNotFound(d)),
+			}
+		};
+	}
+	Ok(days::with_days!(solver_match))
+}
+
+/// Runs `part` on a solver from [`initialize`], returning the answer and the time it took.
+pub fn run_part(
+	solver: &mut dyn SolverSafe,
+	part: u32,
+	dbg: u8,
+	ctx: &Context,
+) -> Res<(Duration, Answer)> {
+	match part {
+		1 => Ok(solver.part_one(dbg, ctx)),
+		2 => Ok(solver.part_two(dbg, ctx)),
+		p => solver.run_any(p, dbg),
+	}
+}
@@ -5,17 +5,16 @@ use std::num::Saturating;
 use std::ops::{Add, AddAssign, Div, Mul, Sub};
 use std::str::FromStr;
 
-pub use crate::{AocError, Res, Solver};
+pub use crate::{Answer, AocError, Context, Res, Solver};
 
-pub use std::array::{from_fn as from_fn_array, try_from_fn};
+pub use std::array::from_fn as from_fn_array;
 pub use std::cmp::Reverse;
 pub use std::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
 pub use std::convert::identity;
 pub use std::fmt::{Debug, Display};
 pub use std::iter::{
-	empty as empty_iter, from_coroutine as gen_iter, from_fn as from_fn_iter, once as once_iter,
-	once_with as once_with_iter, repeat as repeat_iter, repeat_with as repeat_with_iter,
-	successors,
+	empty as empty_iter, from_fn as from_fn_iter, once as once_iter, once_with as once_with_iter,
+	repeat as repeat_iter, repeat_with as repeat_with_iter, successors,
 };
 pub use std::mem::{replace, swap, take};
 pub use std::num::Wrapping;
@@ -54,6 +53,9 @@ pub use more_itertools::*;
 mod numeric;
 pub use numeric::*;
 
+mod compat;
+pub use compat::*;
+
 /// Computes the triangular number.
 ///
 /// # Example
@@ -114,7 +116,7 @@ where
 ///
 /// ```
 /// # use aoc2023::helpers::is;
-/// assert!(is("hello")("hello"));
+/// assert!(is("hello")(&"hello"));
 /// ```
 pub fn is<T>(byte: T) -> impl for<'b> Fn(&'b T) -> bool
 where
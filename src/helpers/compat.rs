@@ -0,0 +1,121 @@
+//! Stable versions of nightly standard library APIs. With the `nightly` feature, these use the
+//! standard library instead where it has the same API.
+
+/// Creates an iterator from a coroutine. Only available with the `nightly` feature.
+#[cfg(feature = "nightly")]
+pub use std::iter::from_coroutine as gen_iter;
+
+#[cfg(feature = "nightly")]
+pub use std::array::try_from_fn;
+
+/// Creates an array by calling `f` with each index, stopping at the first error.
+///
+/// Stable version of [`std::array::try_from_fn`], but only for `Result`.
+///
+/// # Example
+///
+/// ```
+/// # use aoc2023::helpers::try_from_fn;
+/// let a: Result<[usize; 3], ()> = try_from_fn(|i| Ok(i * 2));
+/// assert_eq!(a, Ok([0, 2, 4]));
+/// let b: Result<[usize; 3], usize> = try_from_fn(|i| if i < 2 { Ok(i) } else { Err(i) });
+/// assert_eq!(b, Err(2));
+/// ```
+#[cfg(not(feature = "nightly"))]
+pub fn try_from_fn<T, E, F, const N: usize>(mut f: F) -> Result<[T; N], E>
+where
+	F: FnMut(usize) -> Result<T, E>,
+{
+	let mut items = arrayvec::ArrayVec::<T, N>::new();
+	for i in 0..N {
+		items.push(f(i)?);
+	}
+	Ok(items
+		.into_inner()
+		.unwrap_or_else(|_| unreachable!("all {N} items were pushed")))
+}
+
+/// Splitting a slice at the first element that matches.
+pub trait SplitOnce<T> {
+	/// Splits at the first element that matches `pred`, leaving it out of both halves.
+	///
+	/// Stable version of the unstable `<[T]>::split_once`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use aoc2023::helpers::{is, SplitOnce};
+	/// let (a, b) = b"one two three".split_once_by(is(b' ')).unwrap();
+	/// assert_eq!((a, b), (&b"one"[..], &b"two three"[..]));
+	/// assert_eq!(b"none".split_once_by(is(b' ')), None);
+	/// ```
+	fn split_once_by<F>(&self, pred: F) -> Option<(&[T], &[T])>
+	where
+		F: FnMut(&T) -> bool;
+}
+
+impl<T> SplitOnce<T> for [T] {
+	#[cfg(feature = "nightly")]
+	fn split_once_by<F>(&self, pred: F) -> Option<(&[T], &[T])>
+	where
+		F: FnMut(&T) -> bool,
+	{
+		self.split_once(pred)
+	}
+
+	#[cfg(not(feature = "nightly"))]
+	fn split_once_by<F>(&self, pred: F) -> Option<(&[T], &[T])>
+	where
+		F: FnMut(&T) -> bool,
+	{
+		let index = self.iter().position(pred)?;
+		Some((&self[..index], &self[index + 1..]))
+	}
+}
+
+/// Taking elements off the ends of a slice, like the unstable `slice_take` methods.
+pub trait SliceTake<'a, T> {
+	/// Removes the first element and returns it.
+	fn pop_first(&mut self) -> Option<&'a T>;
+
+	/// Removes the last element and returns it.
+	fn pop_last(&mut self) -> Option<&'a T>;
+
+	/// Removes the first `len` elements and returns them. Returns `None` and leaves the slice
+	/// alone if it is shorter than `len`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use aoc2023::helpers::SliceTake;
+	/// let mut s = &[1, 2, 3, 4][..];
+	/// assert_eq!(s.pop_prefix(3), Some(&[1, 2, 3][..]));
+	/// assert_eq!(s.pop_prefix(2), None);
+	/// assert_eq!(s.pop_first(), Some(&4));
+	/// assert_eq!(s.pop_last(), None);
+	/// ```
+	fn pop_prefix(&mut self, len: usize) -> Option<&'a [T]>;
+}
+
+impl<'a, T> SliceTake<'a, T> for &'a [T] {
+	fn pop_first(&mut self) -> Option<&'a T> {
+		let (first, rest) = self.split_first()?;
+		*self = rest;
+		Some(first)
+	}
+
+	fn pop_last(&mut self) -> Option<&'a T> {
+		let (last, rest) = self.split_last()?;
+		*self = rest;
+		Some(last)
+	}
+
+	fn pop_prefix(&mut self, len: usize) -> Option<&'a [T]> {
+		if len > self.len() {
+			return None;
+		}
+		let (prefix, rest) = self.split_at(len);
+		*self = rest;
+		Some(prefix)
+	}
+}
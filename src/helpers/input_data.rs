@@ -1,6 +1,8 @@
 use std::iter::Filter;
 use std::slice::Split;
 
+use super::SliceTake;
+
 /// Type of the grid returned by [`InputData::grid`].
 pub type Grid<G> = Vec<Vec<G>>;
 
@@ -115,7 +117,7 @@ fn advance_to_newline(mut current: &[u8], skip: Result<usize, usize>) -> Option<
 	}
 
 	loop {
-		let &first = current.take_first()?;
+		let &first = current.pop_first()?;
 		if first == b'\n' {
 			break;
 		}
@@ -317,7 +319,7 @@ where
 				break Some(item);
 			}
 
-			if slice.take_first().is_none() {
+			if slice.pop_first().is_none() {
 				self.slice = None;
 				break Some(original);
 			}
@@ -347,7 +349,7 @@ where
 			// println!("{}", DisplaySlice(end_slice));
 
 			if let Some(len) = delimiter.starts_with_delimiter(end_slice) {
-				slice.take(index..).unwrap();
+				*slice = &slice[..index];
 				break Some(&end_slice[len..]);
 			}
 		}
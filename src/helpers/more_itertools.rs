@@ -1,9 +1,11 @@
+use super::try_from_fn;
+
 pub trait MoreItertools: Iterator {
 	fn array<const N: usize>(mut self) -> Option<[Self::Item; N]>
 	where
 		Self: Sized,
 	{
-		let a = std::array::try_from_fn(|_| self.next())?;
+		let a = try_from_fn(|_| self.next().ok_or(())).ok()?;
 		if self.next().is_some() {
 			None
 		} else {
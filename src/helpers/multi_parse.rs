@@ -1,11 +1,10 @@
-use std::array;
 use std::collections::{BTreeSet, BinaryHeap, HashSet, LinkedList, VecDeque};
 use std::fmt::Display;
 use std::hash::Hash;
 
 use ahash::AHashSet;
 
-use super::{FromBytes, ParseBytes};
+use super::{try_from_fn, FromBytes, ParseBytes};
 
 /// Error type for [`multi_parse`](MultiParseBytes::multi_parse).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -41,28 +40,28 @@ impl Display for MultiParseError {
 /// With a [`Vec`]:
 /// ```
 /// # use aoc2023::helpers::{MultiParseBytes, is};
-/// let vec: Vec<usize> = b"1,2,3".split(is(&b',')).multi_parse().unwrap();
+/// let vec: Vec<usize> = b"1,2,3".split(is(b',')).multi_parse().unwrap();
 /// assert_eq!(vec, vec![1, 2, 3]);
 /// ```
 ///
 /// With an [prim@array]:
 /// ```
 /// # use aoc2023::helpers::{MultiParseBytes, is};
-/// let arr: [usize; 3] = b"1,2,3".split(is(&b',')).multi_parse().unwrap();
+/// let arr: [usize; 3] = b"1,2,3".split(is(b',')).multi_parse().unwrap();
 /// assert_eq!(arr, [1, 2, 3]);
 /// ```
 ///
 /// With a [tuple]:
 /// ```
 /// # use aoc2023::helpers::{MultiParseBytes, is};
-/// let tup: (usize, usize, usize) = b"1,2,3".split(is(&b',')).multi_parse().unwrap();
+/// let tup: (usize, usize, usize) = b"1,2,3".split(is(b',')).multi_parse().unwrap();
 /// assert_eq!(tup, (1, 2, 3));
 /// ```
 ///
 /// Tuples can also have elements with different types:
 /// ```
 /// # use aoc2023::helpers::{MultiParseBytes, is};
-/// let tup: (usize, String, f32) = b"1,2,3".split(is(&b',')).multi_parse().unwrap();
+/// let tup: (usize, String, f32) = b"1,2,3".split(is(b',')).multi_parse().unwrap();
 /// assert_eq!(tup, (1, "2".to_string(), 3.0));
 /// ```
 ///
@@ -70,10 +69,10 @@ impl Display for MultiParseError {
 /// return them from) a function at some point, their types can be inferred completely:
 /// ```
 /// # use aoc2023::helpers::{MultiParseBytes, is};
-/// let (a, b, c): (char, f64, u8) = b"1,2,3".split(is(&b',')).multi_parse().unwrap();
+/// let (a, b, c): (char, f64, u8) = b"1,2,3".split(is(b',')).multi_parse().unwrap();
 /// assert!(a == '1' && b == 2.0 && c == 3);
 ///
-/// let [a, b, c]: [i128; 3] = b"1,2,3".split(is(&b',')).multi_parse().unwrap();
+/// let [a, b, c]: [i128; 3] = b"1,2,3".split(is(b',')).multi_parse().unwrap();
 /// assert_eq!(a - b + c, 2);
 /// ```
 pub trait MultiParseBytes {
@@ -105,14 +104,14 @@ impl<I: IntoIterator<Item = S>, S: AsRef<[u8]>> MultiParseBytes for I {
 /// # Examples
 /// ```
 /// # use aoc2023::helpers::{MultiFromBytes, is};
-/// let tup: (u16, String) = MultiFromBytes::multi_from_bytes(b"3,hello".split(is(&b','))).unwrap();
+/// let tup: (u16, String) = MultiFromBytes::multi_from_bytes(b"3,hello".split(is(b','))).unwrap();
 /// assert_eq!(tup, (3, "hello".to_string()));
 /// ```
 ///
 /// Here is the same thing, but using [`multi_parse`](MultiParseBytes::multi_parse):
 /// ```
 /// # use aoc2023::helpers::{MultiParseBytes, is};
-/// let tup: (u16, String) = b"3,hello".split(is(&b',')).multi_parse().unwrap();
+/// let tup: (u16, String) = b"3,hello".split(is(b',')).multi_parse().unwrap();
 /// assert_eq!(tup, (3, "hello".to_string()));
 /// ```
 pub trait MultiFromBytes {
@@ -157,7 +156,7 @@ impl<T: FromBytes, const N: usize> MultiFromBytes for [T; N] {
 		S: AsRef<[u8]>,
 	{
 		let mut iter = iter.into_iter();
-		array::try_from_fn(|_| {
+		try_from_fn(|_| {
 			iter.next()
 				.ok_or(MultiParseError::NotEnoughItems)
 				.and_then(|s| s.as_ref().parse().ok_or(MultiParseError::ParseError))
@@ -15,7 +15,7 @@ pub trait GetNeighbors {
 	type Neighbor;
 
 	/// The 3x3 neighbors centered on the selected coordinate.
-	fn neighbors(&self, y: usize, x: usize) -> Neighbors<Self::Neighbor> {
+	fn neighbors(&self, y: usize, x: usize) -> Neighbors<'_, Self::Neighbor> {
 		self.neighbors_extra(y, x)
 	}
 
@@ -25,7 +25,7 @@ pub trait GetNeighbors {
 		&self,
 		y: usize,
 		x: usize,
-	) -> NeighborsExtra<Y_LEN, X_LEN, Self::Neighbor> {
+	) -> NeighborsExtra<'_, Y_LEN, X_LEN, Self::Neighbor> {
 		self.neighbors_extra_offset(y, x, (Y_LEN as isize - 1) / -2, (X_LEN as isize - 1) / -2)
 	}
 
@@ -37,10 +37,10 @@ pub trait GetNeighbors {
 		x: usize,
 		off_y: isize,
 		off_x: isize,
-	) -> NeighborsExtra<Y_LEN, X_LEN, Self::Neighbor>;
+	) -> NeighborsExtra<'_, Y_LEN, X_LEN, Self::Neighbor>;
 
 	/// Iterator over the 3x3 neighbors of an element, including itself.
-	fn neighbors_iter(&self, y: usize, x: usize) -> NeighborIter<Self::Neighbor, 3, 3> {
+	fn neighbors_iter(&self, y: usize, x: usize) -> NeighborIter<'_, Self::Neighbor, 3, 3> {
 		self.neighbors(y, x).into_iter().flatten().flatten()
 	}
 
@@ -49,7 +49,7 @@ pub trait GetNeighbors {
 		&self,
 		y: usize,
 		x: usize,
-	) -> NeighborIter<Self::Neighbor, Y_LEN, X_LEN> {
+	) -> NeighborIter<'_, Self::Neighbor, Y_LEN, X_LEN> {
 		self.neighbors_extra::<Y_LEN, X_LEN>(y, x)
 			.into_iter()
 			.flatten()
@@ -63,7 +63,7 @@ pub trait GetNeighbors {
 		x: usize,
 		off_y: isize,
 		off_x: isize,
-	) -> NeighborIter<Self::Neighbor, Y_LEN, X_LEN> {
+	) -> NeighborIter<'_, Self::Neighbor, Y_LEN, X_LEN> {
 		self.neighbors_extra_offset::<Y_LEN, X_LEN>(y, x, off_y, off_x)
 			.into_iter()
 			.flatten()
@@ -78,7 +78,7 @@ pub trait GetNeighbors {
 		x: usize,
 		off_y: isize,
 		off_x: isize,
-	) -> NeighborCoords<Self::Neighbor, Y_LEN, X_LEN> {
+	) -> NeighborCoords<'_, Self::Neighbor, Y_LEN, X_LEN> {
 		NeighborCoords::new(
 			self.neighbors_extra_offset::<Y_LEN, X_LEN>(y, x, off_y, off_x),
 			y as isize + off_y,
@@ -92,13 +92,13 @@ pub trait GetNeighbors {
 		&self,
 		y: usize,
 		x: usize,
-	) -> NeighborCoords<Self::Neighbor, Y_LEN, X_LEN> {
+	) -> NeighborCoords<'_, Self::Neighbor, Y_LEN, X_LEN> {
 		self.neighbors_extra_offset_coords(y, x, -1, -1)
 	}
 
 	/// Iterator over all the elements from [`GetNeighbors::neighbors`] with
 	/// coordinates.
-	fn neighbors_coords(&self, y: usize, x: usize) -> NeighborCoords<Self::Neighbor, 3, 3> {
+	fn neighbors_coords(&self, y: usize, x: usize) -> NeighborCoords<'_, Self::Neighbor, 3, 3> {
 		self.neighbors_extra_coords(y, x)
 	}
 }
@@ -163,7 +163,7 @@ impl<T> GetNeighbors for Grid<T> {
 		x: usize,
 		off_y: isize,
 		off_x: isize,
-	) -> NeighborsExtra<Y_LEN, X_LEN, Self::Neighbor> {
+	) -> NeighborsExtra<'_, Y_LEN, X_LEN, Self::Neighbor> {
 		array::from_fn(|dy| {
 			let ny = y + dy;
 			// let ny = (ny as isize + off_y) as usize;
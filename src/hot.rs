@@ -0,0 +1,158 @@
+//! The C ABI that hot-reloaded days are loaded through. The `hot_day` crate builds a single day as
+//! a `cdylib` and exports these functions with [`export_hot_day`], and the runner's hot mode loads
+//! that library with `dlopen`.
+//!
+//! Answers cross the boundary as JSON of a `Result<Answer, String>`, so panics and errors in the
+//! library are reported instead of unwinding into the runner. Strings and solvers are freed by the
+//! library that made them. Progress and cancellation don't cross, so parts get a fresh
+//! [`Context`].
+
+use std::ffi::{c_char, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr::null_mut;
+
+use crate::solution::SolverSafe;
+use crate::{facade, Answer, Context};
+
+/// Changes whenever the functions below change, so the runner doesn't load a stale library.
+pub const ABI_VERSION: u32 = 1;
+
+/// Names of the exported functions, with the nul terminator that `dlsym` needs.
+pub mod symbols {
+	pub const ABI_VERSION: &[u8] = b"aoc_hot_abi_version\0";
+	pub const DAY: &[u8] = b"aoc_hot_day\0";
+	pub const INITIALIZE: &[u8] = b"aoc_hot_initialize\0";
+	pub const RUN_PART: &[u8] = b"aoc_hot_run_part\0";
+	pub const FREE_SOLVER: &[u8] = b"aoc_hot_free_solver\0";
+	pub const FREE_STRING: &[u8] = b"aoc_hot_free_string\0";
+}
+
+pub type AbiVersionFn = unsafe extern "C" fn() -> u32;
+pub type DayFn = unsafe extern "C" fn() -> u32;
+pub type InitializeFn =
+	unsafe extern "C" fn(input: *const u8, len: usize, dbg: u8, time_ns: *mut u64) -> *mut Handle;
+pub type RunPartFn =
+	unsafe extern "C" fn(solver: *mut Handle, part: u32, dbg: u8, time_ns: *mut u64) -> *mut c_char;
+pub type FreeSolverFn = unsafe extern "C" fn(solver: *mut Handle);
+pub type FreeStringFn = unsafe extern "C" fn(string: *mut c_char);
+
+/// A solver owned by the library. The runner only ever holds a pointer to it.
+pub struct Handle(Box<dyn SolverSafe>);
+
+/// Exports the hot reloading functions for the one day compiled into the calling crate.
+#[macro_export]
+macro_rules! export_hot_day {
+	() => {
+		#[no_mangle]
+		pub extern "C" fn aoc_hot_abi_version() -> u32 {
+			$crate::hot::ABI_VERSION
+		}
+
+		#[no_mangle]
+		pub extern "C" fn aoc_hot_day() -> u32 {
+			$crate::hot::day()
+		}
+
+		/// # Safety
+		/// See [`aoc2023::hot::initialize`].
+		#[no_mangle]
+		pub unsafe extern "C" fn aoc_hot_initialize(
+			input: *const u8,
+			len: usize,
+			dbg: u8,
+			time_ns: *mut u64,
+		) -> *mut $crate::hot::Handle {
+			$crate::hot::initialize(input, len, dbg, time_ns)
+		}
+
+		/// # Safety
+		/// See [`aoc2023::hot::run_part`].
+		#[no_mangle]
+		pub unsafe extern "C" fn aoc_hot_run_part(
+			solver: *mut $crate::hot::Handle,
+			part: u32,
+			dbg: u8,
+			time_ns: *mut u64,
+		) -> *mut ::std::ffi::c_char {
+			$crate::hot::run_part(solver, part, dbg, time_ns)
+		}
+
+		/// # Safety
+		/// See [`aoc2023::hot::free_solver`].
+		#[no_mangle]
+		pub unsafe extern "C" fn aoc_hot_free_solver(solver: *mut $crate::hot::Handle) {
+			$crate::hot::free_solver(solver)
+		}
+
+		/// # Safety
+		/// See [`aoc2023::hot::free_string`].
+		#[no_mangle]
+		pub unsafe extern "C" fn aoc_hot_free_string(string: *mut ::std::ffi::c_char) {
+			$crate::hot::free_string(string)
+		}
+	};
+}
+
+/// The day compiled into this library, or 0 if there isn't one.
+pub fn day() -> u32 {
+	facade::days().next().unwrap_or(0)
+}
+
+/// Creates a solver for [`day`] and writes how long that took to `time_ns`. Returns null if
+/// initializing fails or panics.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes, and `time_ns` must be writable.
+pub unsafe fn initialize(input: *const u8, len: usize, dbg: u8, time_ns: *mut u64) -> *mut Handle {
+	let input = std::slice::from_raw_parts(input, len).to_vec();
+	match catch_unwind(|| facade::initialize(day(), input, dbg)) {
+		Ok(Ok((time, solver))) => {
+			*time_ns = time.as_nanos() as u64;
+			Box::into_raw(Box::new(Handle(solver)))
+		}
+		Ok(Err(e)) => {
+			eprintln!("{e}");
+			null_mut()
+		}
+		Err(_) => null_mut(),
+	}
+}
+
+/// Runs `part` and writes how long it took to `time_ns`. Returns the answer or error as a JSON
+/// string, which has to be freed with [`free_string`].
+///
+/// # Safety
+/// `solver` must come from [`initialize`] and not be freed yet, and `time_ns` must be writable.
+pub unsafe fn run_part(solver: *mut Handle, part: u32, dbg: u8, time_ns: *mut u64) -> *mut c_char {
+	let solver = &mut *(*solver).0;
+	let res = catch_unwind(AssertUnwindSafe(|| {
+		facade::run_part(solver, part, dbg, &Context::new())
+	}));
+	let res: Result<Answer, String> = match res {
+		Ok(Ok((time, answer))) => {
+			*time_ns = time.as_nanos() as u64;
+			Ok(answer)
+		}
+		Ok(Err(e)) => Err(e.to_string()),
+		Err(_) => Err(format!("Part {part} panicked")),
+	};
+	let json = serde_json::to_string(&res).expect("answers always serialize");
+	// JSON escapes nul bytes, so there can't be one in the string
+	CString::new(json).unwrap().into_raw()
+}
+
+/// Frees a solver from [`initialize`].
+///
+/// # Safety
+/// `solver` must come from [`initialize`] and not be freed yet.
+pub unsafe fn free_solver(solver: *mut Handle) {
+	drop(Box::from_raw(solver));
+}
+
+/// Frees a string from [`run_part`].
+///
+/// # Safety
+/// `string` must come from [`run_part`] and not be freed yet.
+pub unsafe fn free_string(string: *mut c_char) {
+	drop(CString::from_raw(string));
+}
@@ -1,29 +1,34 @@
-#![feature(type_alias_impl_trait)]
-#![feature(array_windows)]
-#![feature(array_chunks)]
-#![feature(array_try_from_fn)]
-#![feature(byte_slice_trim_ascii)]
-#![feature(slice_take)]
-#![feature(iter_array_chunks)]
-#![feature(iter_collect_into)]
-#![feature(get_many_mut)]
-#![feature(split_as_slice)]
-#![feature(coroutines)]
-#![feature(iter_from_coroutine)]
-#![feature(slice_split_once)]
-#![feature(impl_trait_in_assoc_type)]
-#![feature(slice_group_by)]
-#![feature(iter_advance_by)]
-#![feature(array_try_map)]
-#![feature(slice_first_last_chunk)]
+//! Advent of Code 2023 solutions and the runner for them.
+//!
+//! Other programs can get answers from [`solve`] and the rest of the [`facade`] module, which
+//! don't print or read files.
+//!
+//! This builds on stable Rust. The `nightly` feature uses the standard library versions of the
+//! helpers in `helpers::compat` instead of their stable fallbacks, and adds `helpers::gen_iter`.
+
+#![cfg_attr(
+	feature = "nightly",
+	feature(coroutines, iter_from_coroutine, slice_split_once, array_try_from_fn)
+)]
+// `coroutines` is only used by days that call `helpers::gen_iter`
+#![cfg_attr(feature = "nightly", allow(unknown_lints, unused_features))]
 
 pub const YEAR: u32 = 2023;
 pub type Res<T> = Result<T, AocError>;
+pub mod answer;
+pub use answer::Answer;
 pub mod solution;
 pub use solution::Solver;
+pub mod context;
+pub use context::Context;
 mod error;
 pub use error::AocError;
 pub mod helpers;
+pub mod hot;
+pub mod normalize;
+pub mod release;
 pub mod runner;
 
 pub mod days;
+pub mod facade;
+pub use facade::solve;
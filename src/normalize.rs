@@ -0,0 +1,143 @@
+//! Input normalization, run on every input before it is given to
+//! [`Solver::initialize`](crate::Solver::initialize).
+//!
+//! Inputs that went through an editor or a Windows machine can have a byte order mark, `\r\n`
+//! line endings, or no trailing newline. Solvers (and the [`InputData`](crate::helpers::InputData)
+//! helpers) assume none of those, so they are fixed here instead.
+
+use std::fmt::Display;
+
+use clap::ValueEnum;
+
+/// A single normalization step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, ValueEnum)]
+pub enum Normalization {
+	/// Remove a UTF-8 byte order mark at the start.
+	Bom,
+	/// Replace `\r\n` line endings with `\n`.
+	Crlf,
+	/// Add a `\n` at the end if there isn't one.
+	TrailingNewline,
+	/// Don't normalize. Overrides the other steps.
+	Off,
+}
+
+impl Normalization {
+	/// The steps used by default.
+	pub const DEFAULT: [Self; 3] = [Self::Bom, Self::Crlf, Self::TrailingNewline];
+}
+
+/// What [`normalize`] changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NormalizeReport {
+	pub removed_bom: bool,
+	pub replaced_crlf: usize,
+	pub added_trailing_newline: bool,
+}
+
+impl NormalizeReport {
+	/// Returns true if the input was already canonical.
+	pub fn is_unchanged(&self) -> bool {
+		*self == Self::default()
+	}
+}
+
+impl Display for NormalizeReport {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let mut changes = Vec::new();
+		if self.removed_bom {
+			changes.push("removed byte order mark".to_string());
+		}
+		if self.replaced_crlf > 0 {
+			changes.push(format!("replaced {} CRLF line endings", self.replaced_crlf));
+		}
+		if self.added_trailing_newline {
+			changes.push("added trailing newline".to_string());
+		}
+		if changes.is_empty() {
+			f.write_str("unchanged")
+		} else {
+			f.write_str(&changes.join(", "))
+		}
+	}
+}
+
+const BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Applies `steps` to `input` in place and reports what changed.
+///
+/// # Example
+///
+/// ```
+/// # use aoc2023::normalize::{normalize, Normalization};
+/// let mut input = b"\xEF\xBB\xBFab\r\ncd".to_vec();
+/// let report = normalize(&mut input, &Normalization::DEFAULT);
+/// assert_eq!(input, b"ab\ncd\n");
+/// assert_eq!(report.replaced_crlf, 1);
+/// ```
+pub fn normalize(input: &mut Vec<u8>, steps: &[Normalization]) -> NormalizeReport {
+	let mut report = NormalizeReport::default();
+	if steps.contains(&Normalization::Off) {
+		return report;
+	}
+
+	if steps.contains(&Normalization::Bom) && input.starts_with(BOM) {
+		input.drain(..BOM.len());
+		report.removed_bom = true;
+	}
+
+	if steps.contains(&Normalization::Crlf) {
+		// Compacts the input in place. `write` never passes `read`, so the byte after `read` is
+		// still original.
+		let mut write = 0;
+		for read in 0..input.len() {
+			let b = input[read];
+			if b == b'\r' && input.get(read + 1) == Some(&b'\n') {
+				report.replaced_crlf += 1;
+				continue;
+			}
+			input[write] = b;
+			write += 1;
+		}
+		input.truncate(write);
+	}
+
+	if steps.contains(&Normalization::TrailingNewline)
+		&& !input.is_empty()
+		&& !input.ends_with(b"\n")
+	{
+		input.push(b'\n');
+		report.added_trailing_newline = true;
+	}
+
+	report
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn canonical_input_is_unchanged() {
+		let mut input = b"abc\ndef\n".to_vec();
+		let report = normalize(&mut input, &Normalization::DEFAULT);
+		assert!(report.is_unchanged());
+		assert_eq!(input, b"abc\ndef\n");
+	}
+
+	#[test]
+	fn lone_carriage_returns_are_kept() {
+		let mut input = b"a\rb\r\nc\r".to_vec();
+		let report = normalize(&mut input, &[Normalization::Crlf]);
+		assert_eq!(report.replaced_crlf, 1);
+		assert_eq!(input, b"a\rb\nc\r");
+	}
+
+	#[test]
+	fn off_overrides_other_steps() {
+		let mut input = b"\xEF\xBB\xBFa\r\nb".to_vec();
+		let report = normalize(&mut input, &[Normalization::Bom, Normalization::Off]);
+		assert!(report.is_unchanged());
+		assert_eq!(input, b"\xEF\xBB\xBFa\r\nb");
+	}
+}
@@ -0,0 +1,35 @@
+//! Puzzle release times.
+//!
+//! Puzzles unlock at midnight in America/New_York. The time zone rules come from the tz database
+//! embedded by `chrono-tz`, so this follows US daylight saving changes without a hard-coded
+//! offset and without network access.
+
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use chrono_tz::America::New_York;
+
+/// Returns the instant the puzzle and input for `day` of `year` unlock. Returns `None` if the
+/// date doesn't exist (`day` isn't in `1..=31`).
+///
+/// # Example
+///
+/// ```
+/// # use aoc2023::release::unlock_time;
+/// let unlock = unlock_time(2023, 1).unwrap();
+/// assert_eq!(unlock.to_rfc3339(), "2023-12-01T05:00:00+00:00");
+/// ```
+pub fn unlock_time(year: i32, day: u32) -> Option<DateTime<Utc>> {
+	let midnight = NaiveDate::from_ymd_opt(year, 12, day)?.and_hms_opt(0, 0, 0)?;
+	// Midnight is never skipped or repeated in New York, so this is always a single time.
+	let local = New_York.from_local_datetime(&midnight).single()?;
+	Some(local.with_timezone(&Utc))
+}
+
+/// Returns how long until `day` of `year` unlocks. This is negative if it already has.
+pub fn time_until_unlock(year: i32, day: u32) -> Option<Duration> {
+	Some(unlock_time(year, day)? - Utc::now())
+}
+
+/// Returns the first day of `year` that hasn't unlocked yet, if there is one.
+pub fn next_unlock(year: i32) -> Option<u32> {
+	(1..=25).find(|&day| time_until_unlock(year, day).is_some_and(|t| t > Duration::zero()))
+}
@@ -1,21 +1,44 @@
-use chrono::{Duration as ChDuration, FixedOffset, NaiveDate, Utc};
+use chrono::Duration as ChDuration;
+use clap::builder::RangedU64ValueParser;
 use clap::{ArgAction, Command, Parser, ValueEnum};
 use clap_complete::Shell;
 use itertools::Itertools;
 use regex::bytes::Regex;
 use ureq::{Agent, AgentBuilder};
 
-use std::borrow::Cow;
 use std::fmt::Display;
 use std::fs::{create_dir_all, File};
 use std::hint::black_box;
-use std::io::{stdout, BufWriter};
+use std::io::{stdout, BufWriter, Read};
 use std::path::PathBuf;
 use std::sync::OnceLock;
 use std::time::{Duration, Instant};
 
+use crate::normalize::{normalize, Normalization};
 use crate::solution::SolverSafe;
-use crate::{AocError, Res, Solver, YEAR};
+#[cfg(any_day)]
+use crate::Solver;
+use crate::{facade, release, Answer, AocError, Context, Res, YEAR};
+
+mod bisect;
+mod cache;
+mod determinism;
+mod embedded;
+mod external;
+#[cfg(feature = "hot-reload")]
+mod hot;
+mod leaderboard;
+mod memory;
+mod progress;
+mod report;
+mod scaffold;
+mod serve;
+mod stats;
+mod tui;
+use cache::AnswerCache;
+use external::External;
+use memory::AllocStats;
+use progress::Stop;
 
 /// User agent (see [Eric's post on the
 /// subreddit](https://www.reddit.com/r/adventofcode/comments/z9dhtd))
@@ -53,6 +76,17 @@ pub struct Settings {
 	#[arg(short = 'c', long = "bench-count", default_value_t = 0)]
 	pub bench_count: usize,
 
+	/// Write a benchmark report to this path.
+	///
+	/// In bench mode, writes `PATH.csv` with per-day statistics and `PATH.html`, a self-contained
+	/// page with tables and charts.
+	#[arg(long, value_name = "PATH")]
+	pub report: Option<PathBuf>,
+
+	/// Compare the benchmark report against an earlier report's CSV file.
+	#[arg(long, value_name = "CSV")]
+	pub baseline: Option<PathBuf>,
+
 	/// Hide answers in output.
 	#[arg(short = 'a', long)]
 	pub hide_answers: bool,
@@ -80,10 +114,100 @@ pub struct Settings {
 	#[arg(short, long, action = ArgAction::Count)]
 	pub runner_debug: u8,
 
+	/// Give each part a freshly initialized solver instead of sharing one between parts.
+	///
+	/// In run mode this also runs the parts on a shared solver and reports any answers that differ,
+	/// which means the solver leaks state from one part into the next.
+	#[arg(long)]
+	pub isolate: bool,
+
+	/// Specify how many times each day is run in determinism mode, or each commit in bisect mode.
+	#[arg(long, default_value_t = 5, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+	pub runs: usize,
+
+	/// Use a different hasher seed for each run in determinism mode.
+	///
+	/// Without this every run uses the same seed, so only thread scheduling can change answers.
+	#[arg(long)]
+	pub random_seeds: bool,
+
+	/// Use a random number of rayon threads for each run in determinism mode.
+	#[arg(long)]
+	pub random_threads: bool,
+
+	/// Read the input from this file instead of the inputs directory.
+	///
+	/// Pass `-` to read from stdin. Overrides `--test`. Only one day can be selected, and answers
+	/// for this input are never saved.
+	#[arg(short, long, value_name = "PATH")]
+	pub input: Option<PathBuf>,
+
+	/// Validate against this answer file instead of the one in the inputs directory.
+	///
+	/// The file has one answer per line, like the saved answer files. Only one day can be selected,
+	/// and the file is never modified.
+	#[arg(long, value_name = "PATH")]
+	pub answers: Option<PathBuf>,
+
+	/// Normalization steps to run on inputs before they are given to the solvers.
+	///
+	/// Pass a comma-separated list, or `off` to use inputs exactly as they are. Any changes are
+	/// reported on stderr.
+	#[arg(long, value_enum, value_delimiter = ',', default_values_t = Normalization::DEFAULT)]
+	pub normalize: Vec<Normalization>,
+
+	/// Use answers cached by earlier runs in run and validate modes.
+	///
+	/// Answers are always cached, keyed by the input, `--debug`, the enabled features, the day's
+	/// source file, and the rest of `src/` and `Cargo.lock`. A day only uses the cache if all of
+	/// its selected parts are cached.
+	#[arg(long)]
+	pub cached: bool,
+
+	/// The commit to start bisecting from, where the part is known to be good.
+	#[arg(long, value_name = "REV")]
+	pub good: Option<String>,
+
+	/// The commit where the part is known to be bad in bisect mode.
+	#[arg(long, value_name = "REV", default_value = "HEAD")]
+	pub bad: String,
+
+	/// In bisect mode, find the first commit where the part takes longer than this many
+	/// milliseconds, instead of the first commit where its answer changed.
+	#[arg(long, value_name = "MS")]
+	pub slower_than: Option<f64>,
+
+	/// The answer at the good commit, passed from bisect mode to each step.
+	#[arg(long, hide = true)]
+	pub bisect_expect: Option<String>,
+
+	/// Specify a number of milliseconds after which a part is asked to stop.
+	///
+	/// Solvers that check for cancellation return their best answer so far. 0 means no timeout.
+	#[arg(long, default_value_t = 0)]
+	pub timeout: u64,
+
+	/// Run the external solver with this name from `external_solvers.json` instead of the Rust
+	/// solutions.
+	///
+	/// Days it doesn't declare are skipped, and its answers are never cached.
+	#[arg(long, value_name = "NAME")]
+	pub solver: Option<String>,
+
+	/// The port to listen on in serve mode. Only connections from localhost are accepted.
+	#[arg(long, default_value_t = 2023)]
+	pub port: u16,
+
 	#[arg(skip = OnceLock::new())]
 	pub client: OnceLock<Agent>,
 	#[arg(skip = OnceLock::new())]
 	pub regex: OnceLock<Regex>,
+	/// Stdin is read once and reused, since it can't be read again for each day.
+	#[arg(skip = OnceLock::new())]
+	pub stdin_input: OnceLock<Vec<u8>>,
+	/// Loaded from `external_solvers.json` when `--solver` is passed or in crosscheck mode.
+	#[arg(skip)]
+	externals: Vec<External>,
 
 	#[arg(long)]
 	pub completions: Option<Shell>,
@@ -111,6 +235,68 @@ pub enum Mode {
 	Prompt,
 	/// Retrieve the prompt and test cases
 	P,
+	Determinism,
+	/// Run the specified days `--runs` times and report any part whose answer changes.
+	///
+	/// Like bench mode, this ignores part info and always runs part one and part two.
+	D,
+	New,
+	/// Generate solution modules for the specified days from `templates/day.rs`.
+	///
+	/// This registers the days in `src/days.rs`, fetches their inputs and prompts, and creates an
+	/// empty answer file for each example found in the prompt.
+	N,
+	/// Fetch personal stats and regenerate the leaderboard table in the README.
+	///
+	/// If `--report` points to an earlier bench report, its medians are added as a column.
+	Stats,
+	Leaderboard,
+	/// Show the standings of the private leaderboards whose IDs are passed instead of days.
+	///
+	/// Leaderboards are cached in `inputs/leaderboard` and fetched at most every 15 minutes.
+	L,
+	Countdown,
+	/// Count down to the release of the specified day, or the next day if none are specified,
+	/// then fetch its input.
+	C,
+	/// Remove the specified days from the answer cache, or the whole cache if no days are given.
+	ClearCache,
+	/// Find the first commit between `--good` and `--bad` that changed the answer of the specified
+	/// part, or made it slower than `--slower-than`.
+	///
+	/// This drives `git bisect run`, building the runner in release mode at each commit and
+	/// keeping the fastest of `--runs` runs. The work tree has to be clean.
+	Bisect,
+	/// Measure the checked out commit for bisect mode and exit with the code `git bisect run`
+	/// expects.
+	#[value(hide = true)]
+	BisectStep,
+	Interactive,
+	/// Browse the days in a terminal interface, and run or validate them with one key.
+	///
+	/// Shows each day's status, its prompt and examples, and the output and debug output of the
+	/// last run in separate panes.
+	I,
+	/// Start an HTTP server on localhost that solves, validates, and benchmarks days for other
+	/// tools, answering with JSON.
+	///
+	/// `GET /days` lists the days, `POST /solve/DAY` solves the input in the request body,
+	/// `GET /validate/DAY` checks the saved input against its answer file, and `POST /bench/DAY`
+	/// streams benchmark progress as JSON lines.
+	Serve,
+	Crosscheck,
+	/// Run the Rust solution and every external solver from `external_solvers.json` on the
+	/// specified days, and report any part where their answers differ.
+	X,
+	#[cfg(feature = "hot-reload")]
+	Hot,
+	/// Build each specified day as a dynamic library, run it, and build, load, and run it again
+	/// whenever something in `src/` changes.
+	///
+	/// Rebuilds only compile the library with that one day in it, not the runner, so the runner can
+	/// be built without the day. Answers are checked against the saved answers but never saved.
+	#[cfg(feature = "hot-reload")]
+	H,
 }
 
 macro_rules! debug_println {
@@ -131,6 +317,19 @@ impl Settings {
 			return Ok(());
 		}
 
+		if self.stops_on_interrupt() {
+			progress::install_interrupt_handler();
+		}
+
+		if self.solver.is_some() || matches!(self.mode, Mode::Crosscheck | Mode::X) {
+			self.externals = external::load()?;
+		}
+		if let Some(name) = &self.solver {
+			if !self.externals.iter().any(|e| e.name == *name) {
+				return Err(AocError::UnknownSolver(name.clone()));
+			}
+		}
+
 		let runner_time = Instant::now();
 		let mut solver_time = Duration::ZERO;
 
@@ -138,9 +337,27 @@ impl Settings {
 		debug_println!(self.runner_debug, 1, "Starting runner");
 
 		let mut day_parts = Vec::new();
-		// Can't use collect because I need to flatten the Vec inside the Result
-		for item in self.days.iter().map(|word| parse_day(word)) {
-			day_parts.extend_from_slice(&item?);
+		let mut leaderboard_ids = Vec::new();
+		// Leaderboard mode takes leaderboard IDs instead of days
+		if matches!(self.mode, Mode::Leaderboard | Mode::L) {
+			for word in &self.days {
+				leaderboard_ids.push(word.parse().map_err(|_| AocError::InvalidLeaderboardId {
+					arg: word.to_string(),
+				})?);
+			}
+		} else {
+			// Can't use collect because I need to flatten the Vec inside the Result
+			for item in self.days.iter().map(|word| parse_day(word)) {
+				day_parts.extend_from_slice(&item?);
+			}
+		}
+		if self.input.is_some() || self.answers.is_some() {
+			if day_parts.iter().map(|(day, _)| day).unique().count() > 1 {
+				return Err(AocError::ExternalFileForManyDays);
+			}
+			if self.answers.is_some() && matches!(self.mode, Mode::Save | Mode::S) {
+				return Err(AocError::SaveToExternalAnswers);
+			}
 		}
 
 		solver_time += match self.mode {
@@ -149,6 +366,19 @@ impl Settings {
 			Mode::Save | Mode::S => self.save(&day_parts),
 			Mode::Validate | Mode::V => self.validate(&day_parts),
 			Mode::Prompt | Mode::P => self.prompt(&day_parts),
+			Mode::Determinism | Mode::D => self.determinism(&day_parts),
+			Mode::New | Mode::N => self.new_days(&day_parts),
+			Mode::Stats => self.stats(),
+			Mode::Leaderboard | Mode::L => self.leaderboard(&leaderboard_ids),
+			Mode::Countdown | Mode::C => self.countdown(&day_parts),
+			Mode::ClearCache => self.clear_cache(&day_parts),
+			Mode::Bisect => self.bisect(&day_parts),
+			Mode::BisectStep => self.bisect_step(&day_parts),
+			Mode::Interactive | Mode::I => self.interactive(),
+			Mode::Serve => self.serve(),
+			Mode::Crosscheck | Mode::X => self.crosscheck(&day_parts),
+			#[cfg(feature = "hot-reload")]
+			Mode::Hot | Mode::H => self.hot(&day_parts),
 		}?;
 
 		let runner_time = runner_time.elapsed();
@@ -162,70 +392,213 @@ impl Settings {
 		Ok(())
 	}
 
+	/// Whether this mode stops cleanly on Ctrl-C. Other modes don't take over Ctrl-C, so it kills
+	/// them right away.
+	fn stops_on_interrupt(&self) -> bool {
+		match self.mode {
+			Mode::Run | Mode::R | Mode::Save | Mode::S | Mode::Validate | Mode::V => true,
+			Mode::Crosscheck | Mode::X | Mode::Countdown | Mode::C => true,
+			#[cfg(feature = "hot-reload")]
+			Mode::Hot | Mode::H => true,
+			_ => false,
+		}
+	}
+
 	fn run_days(&mut self, day_parts: &[(u32, Vec<u32>)]) -> Res<Duration> {
 		let mut test_time = Duration::ZERO;
-		let mut buffer = String::new();
+		let mut leaking = 0;
+		let mut cache = AnswerCache::load()?;
 		for &(day, ref parts) in day_parts {
 			debug_println!(self.runner_debug, 1, "Starting day {day}");
 
 			let mut day_time = Duration::ZERO;
 
-			if !(1..=25).contains(&day) {
-				eprintln!("Day {day} not found, skipping");
+			if !self.runnable(day) {
 				continue;
 			}
 
 			let file = self.get_input(day)?;
-			let (time, mut solver) = day_to_solver(day, file, self.debug)?;
-			print_times(day, 0, "", time);
-			day_time += time;
-
-			if parts.is_empty() {
-				let time = solver.part_one(self.debug, &mut buffer);
-				day_time += time;
+			let parts = if parts.is_empty() {
+				&[1, 2][..]
+			} else {
+				&parts[..]
+			};
 
-				if !self.hide_answers {
-					print_times(day, 1, &buffer, time);
-				} else {
-					print_times(day, 1, "", time);
+			if let Some(entries) = self.cached_answers(&cache, day, parts, &file) {
+				for entry in entries {
+					let answer = if self.hide_answers {
+						&Answer::None
+					} else {
+						&entry.answer
+					};
+					println!(
+						"d{day:02}p{:02}: (cached, took {:?}) {answer}",
+						entry.part,
+						entry.time()
+					);
 				}
-				buffer.clear();
+				println!("d{day:02} total: cached\n");
+				continue;
+			}
 
-				let time = solver.part_two(self.debug, &mut buffer);
-				day_time += time;
+			let input = file.clone();
+			let (mem, solver) = memory::measure(|| self.initialize(day, input));
+			let (time, mut solver) = solver?;
+			print_times(day, 0, "", time, mem);
+			day_time += time;
 
-				if !self.hide_answers {
-					print_times(day, 2, &buffer, time);
-				} else {
-					print_times(day, 2, "", time);
-				}
-				buffer.clear();
-			}
+			let mut isolated_answers = Vec::new();
 
-			for &part in parts {
-				let time = match part {
-					1 => solver.part_one(self.debug, &mut buffer),
-					2 => solver.part_two(self.debug, &mut buffer),
-					p => solver.run_any(p, self.debug, &mut buffer)?,
-				};
+			for (i, &part) in parts.iter().enumerate() {
+				day_time += self.isolate_solver(day, &file, &mut solver, i)?;
+				let (time, mem, stop, answer) = self.run_part(&mut *solver, day, part)?;
 				day_time += time;
+				if stop == Stop::Finished {
+					self.cache_answer(&mut cache, day, part, &file, &answer, time);
+				}
 
 				if !self.hide_answers {
-					print_times(day, part, &buffer, time);
+					print_times(day, part, &answer, time, mem);
 				} else {
-					print_times(day, part, "", time);
+					print_times(day, part, "", time, mem);
 				}
-				buffer.clear();
+				if self.isolate {
+					isolated_answers.push(answer);
+				}
+			}
+
+			if self.isolate {
+				leaking += self.check_isolation(day, file, parts, &isolated_answers)?;
 			}
 
 			println!("d{day:02} total: {day_time:?}\n");
 			test_time += day_time;
 		}
+		cache.save()?;
 		println!("All: {test_time:?}");
+		if leaking > 0 {
+			println!("{leaking} parts gave different answers when sharing a solver");
+		}
 		Ok(test_time)
 	}
 
+	/// With `--isolate`, replaces `solver` with a freshly initialized one for every part after the
+	/// first. Returns the time spent initializing.
+	fn isolate_solver(
+		&self,
+		day: u32,
+		file: &[u8],
+		solver: &mut Box<dyn SolverSafe>,
+		part_index: usize,
+	) -> Res<Duration> {
+		if !self.isolate || part_index == 0 {
+			return Ok(Duration::ZERO);
+		}
+		let (time, fresh) = self.initialize(day, file.to_vec())?;
+		*solver = fresh;
+		Ok(time)
+	}
+
+	/// Runs every part up to the last of `parts` in order on one shared solver and reports each
+	/// answer of `parts` that differs from the isolated run. Returns the number of differing parts.
+	fn check_isolation(
+		&self,
+		day: u32,
+		file: Vec<u8>,
+		parts: &[u32],
+		isolated_answers: &[Answer],
+	) -> Res<u32> {
+		let (_, mut solver) = self.initialize(day, file)?;
+		let mut differing = 0;
+
+		// Earlier parts run first even if they weren't selected, since they're what would leak
+		let last = parts.iter().copied().max().unwrap_or(0);
+		let mut shared_answers = Vec::new();
+		for part in 1..=last {
+			shared_answers.push(self.run_part(&mut *solver, day, part)?.3);
+		}
+
+		for (&part, isolated) in parts.iter().zip(isolated_answers) {
+			let shared = &shared_answers[part as usize - 1];
+			if shared != isolated {
+				println!(
+					"d{day:02}p{part:02}: isolated answer {isolated:?} differs from shared answer {shared:?}"
+				);
+				differing += 1;
+			}
+		}
+
+		if differing == 0 {
+			debug_println!(
+				self.runner_debug,
+				1,
+				"d{day:02}: isolated and shared answers match"
+			);
+		}
+		Ok(differing)
+	}
+
+	/// Runs a single part, drawing a progress bar if the solver reports progress. The solver is
+	/// asked to stop after `--timeout` or when Ctrl-C is pressed. A timed out part still returns
+	/// its partial answer, which shouldn't be saved or checked, but Ctrl-C stops the whole run.
+	fn run_part(
+		&self,
+		solver: &mut dyn SolverSafe,
+		day: u32,
+		part: u32,
+	) -> Res<(Duration, AllocStats, Stop, Answer)> {
+		let ctx = Context::new();
+		let timeout = (self.timeout > 0).then(|| Duration::from_millis(self.timeout));
+		let ((mem, result), stop) = progress::watch(&ctx, timeout, || {
+			memory::measure(|| facade::run_part(solver, part, self.debug, &ctx))
+		});
+		let (time, answer) = result?;
+
+		match stop {
+			Stop::Finished => Ok((time, mem, stop, answer)),
+			Stop::TimedOut => {
+				eprintln!(
+					"d{day:02}p{part:02}: timed out after {}, stopped at {}",
+					readable_time(time, 3),
+					progress::describe(&ctx),
+				);
+				Ok((time, mem, stop, answer))
+			}
+			Stop::Interrupted => {
+				eprintln!(
+					"d{day:02}p{part:02}: interrupted after {}, stopped at {} with partial answer {}",
+					readable_time(time, 3),
+					progress::describe(&ctx),
+					answer,
+				);
+				Err(AocError::Interrupted)
+			}
+		}
+	}
+
+	/// Gets the input for `day` and normalizes it.
 	fn get_input(&mut self, day: u32) -> Res<Vec<u8>> {
+		let input = self.read_input(day)?;
+		Ok(self.normalize_input(day, "input", input))
+	}
+
+	/// Runs the `--normalize` steps on `input`, and reports any changes.
+	fn normalize_input(&self, day: u32, name: &str, mut input: Vec<u8>) -> Vec<u8> {
+		let report = normalize(&mut input, &self.normalize);
+		if !report.is_unchanged() {
+			eprintln!("d{day:02}: normalized {name}: {report}");
+		}
+		input
+	}
+
+	fn read_input(&mut self, day: u32) -> Res<Vec<u8>> {
+		if let Some(path) = &self.input {
+			return self.get_external_input(path.clone());
+		}
+		if let Some(embedded) = self.embedded(day) {
+			return Ok(embedded.input.to_vec());
+		}
+
 		let input_main = input_file_name(day, 0);
 		if !input_main.exists() {
 			let time_until_release = time_until_input_is_released(day);
@@ -264,6 +637,154 @@ impl Settings {
 		Ok(input)
 	}
 
+	/// The input and answers for `day` built in with the `embed-inputs` feature. They stand in for
+	/// the real input, so they aren't used with `--input` or `--test`.
+	fn embedded(&self, day: u32) -> Option<&'static embedded::Embedded> {
+		if self.input.is_some() || self.test > 0 {
+			return None;
+		}
+		embedded::get(day)
+	}
+
+	/// Reads the input passed with `--input`, which is a path or `-` for stdin.
+	fn get_external_input(&self, path: PathBuf) -> Res<Vec<u8>> {
+		if path.as_os_str() != "-" {
+			return Ok(std::fs::read(path)?);
+		}
+
+		if let Some(input) = self.stdin_input.get() {
+			return Ok(input.clone());
+		}
+		let mut input = Vec::new();
+		std::io::stdin().read_to_end(&mut input)?;
+		Ok(self.stdin_input.get_or_init(|| input).clone())
+	}
+
+	/// Returns the answer file for `day`, which is `--answers` if it was passed. An `--input`
+	/// without `--answers` has none, since the saved answers are for the saved input.
+	fn answer_path(&self, day: u32) -> Option<PathBuf> {
+		match (&self.answers, &self.input) {
+			(Some(path), _) => Some(path.clone()),
+			(None, Some(_)) => None,
+			(None, None) => Some(answer_file_name(day, self.test)),
+		}
+	}
+
+	/// The external solver picked with `--solver`, if it solves `day`.
+	fn external(&self, day: u32) -> Option<&External> {
+		let name = self.solver.as_ref()?;
+		self.externals
+			.iter()
+			.find(|e| e.name == *name && e.days.contains(&day))
+	}
+
+	/// Creates the solver for `day`, which is the `--solver` one if it was passed and the Rust
+	/// solution otherwise.
+	fn initialize(&self, day: u32, input: Vec<u8>) -> Res<(Duration, Box<dyn SolverSafe>)> {
+		match self.external(day) {
+			Some(external) => external.initialize(day, &input),
+			None => facade::initialize(day, input, self.debug),
+		}
+	}
+
+	/// Returns whether `day` can run with the selected solver, printing why it's skipped if it
+	/// can't.
+	fn runnable(&self, day: u32) -> bool {
+		match &self.solver {
+			Some(name) if self.external(day).is_none() => {
+				eprintln!("Solver {name} doesn't solve day {day}, skipping");
+				false
+			}
+			Some(_) => true,
+			None => runnable(day),
+		}
+	}
+
+	/// Runs initialization, part one, and part two, timing each one, for bench and determinism
+	/// modes.
+	fn bench_once(&self, day: u32, file: Vec<u8>) -> Res<([Duration; 3], Answer, Answer)> {
+		let Some(external) = self.external(day) else {
+			return day_to_bench(day, file, self.debug);
+		};
+		let ctx = Context::new();
+		let (t0, mut solver) = external.initialize(day, &file)?;
+		let (t1, p1) = solver.part_one(self.debug, &ctx);
+		let (t2, p2) = solver.part_two(self.debug, &ctx);
+		Ok(([t0, t1, t2], p1, p2))
+	}
+
+	/// Counts the allocations of initialization, part one, and part two separately, for bench
+	/// mode.
+	fn measure_steps(&self, day: u32, file: Vec<u8>) -> Res<[AllocStats; 3]> {
+		let (init, solver) = memory::measure(|| self.initialize(day, file));
+		let (_, mut solver) = solver?;
+		let ctx = Context::new();
+		let (p1, res) = memory::measure(|| facade::run_part(&mut *solver, 1, self.debug, &ctx));
+		res?;
+		let (p2, res) = memory::measure(|| facade::run_part(&mut *solver, 2, self.debug, &ctx));
+		res?;
+		Ok([init, p1, p2])
+	}
+
+	/// With `--cached`, returns the cached answers for `parts` if every one of them is cached.
+	/// `--isolate` always runs the solvers, since it checks them rather than their answers.
+	fn cached_answers(
+		&self,
+		cache: &AnswerCache,
+		day: u32,
+		parts: &[u32],
+		file: &[u8],
+	) -> Option<Vec<cache::Entry>> {
+		if !self.cached || self.isolate || self.solver.is_some() {
+			return None;
+		}
+		let input_hash = cache::hash(file);
+		let source_hash = source_hash(day)?;
+		let entries: Option<Vec<_>> = parts
+			.iter()
+			.map(|&part| {
+				cache
+					.get(day, part, input_hash, source_hash, self.debug)
+					.cloned()
+			})
+			.collect();
+		if entries.is_none() {
+			debug_println!(
+				self.runner_debug,
+				1,
+				"d{day:02}: not every part is cached, running"
+			);
+		}
+		entries
+	}
+
+	/// Caches the answer to a part that finished. Answers from `--solver` are never cached.
+	fn cache_answer(
+		&self,
+		cache: &mut AnswerCache,
+		day: u32,
+		part: u32,
+		file: &[u8],
+		answer: &Answer,
+		time: Duration,
+	) {
+		let Some(source_hash) = source_hash(day) else {
+			return;
+		};
+		if self.solver.is_some() {
+			return;
+		}
+		cache.insert(cache::Entry {
+			day,
+			part,
+			input_hash: cache::hash(file),
+			source_hash,
+			dbg: self.debug,
+			answer: answer.clone(),
+			time_ns: time.as_nanos() as u64,
+		});
+	}
+
 	/// Get the input from the network and write it to the filesystem. Will overwrite any existing
 	/// input files.
 	fn get_input_network(&mut self, day: u32) -> Res<()> {
@@ -361,73 +882,133 @@ impl Settings {
 
 		let mut bench_times = Duration::ZERO;
 		let mut total_time = Duration::ZERO;
+		let mut day_benches = Vec::new();
 
 		for &(day, _) in day_parts {
-			let mut day_time = Duration::ZERO;
-
-			if !(1..=25).contains(&day) {
-				eprintln!("Day {day} not found, skipping");
+			if !self.runnable(day) {
 				continue;
 			}
 
 			let file = self.get_input(day)?;
-			let mut a1 = String::new();
-			let mut a2 = String::new();
+			let (avg_time, day_time, samples) =
+				self.bench_input(day, file, &format!("d{day:02}"))?;
+
+			bench_times += avg_time;
+			total_time += day_time;
+			day_benches.push(report::DayBench::new(day, samples));
 
-			// Warmup
-			for _ in 0..10 {
-				let (_, p1, p2) = day_to_bench(day, file.clone(), self.debug)?;
-				black_box((p1, p2));
+			// The corpus is only benchmarked alongside the main input
+			if self.input.is_some() || self.test > 0 {
+				continue;
+			}
+			let mut corpus_avgs = Vec::new();
+			for (name, input, _) in corpus_files(day)? {
+				let file = std::fs::read(input)?;
+				let file = self.normalize_input(day, &format!("corpus input {name:?}"), file);
+				let (avg_time, day_time, _) =
+					self.bench_input(day, file, &format!("d{day:02} [{name}]"))?;
+				total_time += day_time;
+				corpus_avgs.push(avg_time);
 			}
+			if !corpus_avgs.is_empty() {
+				let count = corpus_avgs.len();
+				let mean = corpus_avgs.iter().sum::<Duration>() / count as u32;
+				println!(
+					"d{day:02} corpus: {count} inputs, avg of {:>10}, fastest {:>10}, slowest {:>10}",
+					readable_time(mean, 3),
+					readable_time(*corpus_avgs.iter().min().unwrap(), 3),
+					readable_time(*corpus_avgs.iter().max().unwrap(), 3),
+				);
+			}
+		}
 
-			let runs = if self.bench_count > 0 {
-				for _ in 0..self.bench_count {
-					let (time, p1, p2) = day_to_bench(day, black_box(file.clone()), self.debug)?;
-					(a1, a2) = black_box((p1, p2));
-					day_time += time;
-				}
-				self.bench_count
-			} else {
-				let start = Instant::now();
-				let mut runs = 0;
-				while start.elapsed() < Duration::from_millis(self.bench_time) {
-					runs += 10;
-					for _ in 0..10 {
-						let (time, p1, p2) =
-							day_to_bench(day, black_box(file.clone()), self.debug)?;
-						(a1, a2) = black_box((p1, p2));
-						day_time += time;
-					}
-				}
-				runs
+		println!("All: run avg of {:>22}", readable_time(bench_times, 3));
+
+		if let Some(path) = &self.report {
+			let baseline = match &self.baseline {
+				Some(baseline) => Some(report::read_medians(baseline)?),
+				None => None,
 			};
+			let (csv, html) = report::write_report(path, &day_benches, baseline.as_ref())?;
+			println!("Wrote report to {} and {}", csv.display(), html.display());
+		}
 
-			let avg_time = day_time / runs as _;
+		Ok(total_time)
+	}
 
-			print!(
-				"d{day:02}: ran {runs:>7} times over {:>10} for avg of {:>10}",
-				readable_time(day_time, 3),
-				readable_time(avg_time, 3),
-			);
+	/// Benchmarks one input and prints a line starting with `label`. Returns the average time, the
+	/// total time, and the timings of each run.
+	fn bench_input(
+		&self,
+		day: u32,
+		file: Vec<u8>,
+		label: &str,
+	) -> Res<(Duration, Duration, Vec<[Duration; 3]>)> {
+		let mut day_time = Duration::ZERO;
+		let mut a1 = Answer::None;
+		let mut a2 = Answer::None;
+		let mut samples = Vec::new();
+
+		// Warmup
+		for _ in 0..10 {
+			let (_, p1, p2) = self.bench_once(day, file.clone())?;
+			black_box((p1, p2));
+		}
 
-			if !self.hide_answers {
-				println!(" {:?}", [a1, a2]);
-			} else {
-				println!();
+		let runs = if self.bench_count > 0 {
+			for _ in 0..self.bench_count {
+				let (times, p1, p2) = self.bench_once(day, black_box(file.clone()))?;
+				(a1, a2) = black_box((p1, p2));
+				day_time += times.iter().sum::<Duration>();
+				samples.push(times);
+			}
+			self.bench_count
+		} else {
+			let start = Instant::now();
+			let mut runs = 0;
+			while start.elapsed() < Duration::from_millis(self.bench_time) {
+				runs += 10;
+				for _ in 0..10 {
+					let (times, p1, p2) = self.bench_once(day, black_box(file.clone()))?;
+					(a1, a2) = black_box((p1, p2));
+					day_time += times.iter().sum::<Duration>();
+					samples.push(times);
+				}
 			}
+			runs
+		};
 
-			bench_times += avg_time;
-			total_time += day_time;
+		let avg_time = day_time / runs as _;
+
+		print!(
+			"{label}: ran {runs:>7} times over {:>10} for avg of {:>10}",
+			readable_time(day_time, 3),
+			readable_time(avg_time, 3),
+		);
+
+		if !self.hide_answers {
+			println!(" [{a1}, {a2}]");
+		} else {
+			println!();
 		}
 
-		println!("All: run avg of {:>22}", readable_time(bench_times, 3));
+		// One more run to count allocations, so the counting doesn't slow down the timed runs
+		let mems = self.measure_steps(day, file)?;
+		for (i, mem) in mems.into_iter().enumerate().filter(|(_, mem)| mem.enabled) {
+			let avg = samples.iter().map(|times| times[i]).sum::<Duration>() / samples.len() as _;
+			let step = ["init", "part 1", "part 2"][i];
+			println!("{step:>10}: avg of {:>10}{mem}", readable_time(avg, 3));
+		}
 
-		Ok(total_time)
+		Ok((avg_time, day_time, samples))
 	}
 
 	fn save(&mut self, day_parts: &[(u32, Vec<u32>)]) -> Res<Duration> {
 		let mut time = Duration::ZERO;
 		for &(day, ref parts) in day_parts {
+			if !self.runnable(day) {
+				continue;
+			}
 			time += self.save_day(day, parts)?;
 		}
 		Ok(time)
@@ -436,16 +1017,16 @@ impl Settings {
 	fn save_day(&mut self, day: u32, parts: &[u32]) -> Res<Duration> {
 		let file = self.get_input(day)?;
 
-		let ans_file_name = answer_file_name(day, self.test);
-		let answers = if ans_file_name.exists() {
-			std::fs::read_to_string(&ans_file_name)?
-		} else {
-			String::new()
+		// Save mode never gets `--answers`, so there is only a file for the saved input
+		let ans_file_name = self.answer_path(day);
+		let answers = match &ans_file_name {
+			Some(path) if path.exists() => std::fs::read_to_string(path)?,
+			_ => String::new(),
 		};
-		let mut answer_vec = answers.lines().map(Cow::Borrowed).collect_vec();
+		let mut answer_vec = answers.lines().map(Answer::parse_line).collect_vec();
+		let mut saved_any = false;
 
-		let (mut total_time, mut solver) = day_to_solver(day, file, self.debug)?;
-		let mut buf = String::new();
+		let (mut total_time, mut solver) = self.initialize(day, file.clone())?;
 
 		let parts = if parts.is_empty() {
 			vec![1, 2]
@@ -453,28 +1034,31 @@ impl Settings {
 			parts.to_vec()
 		};
 
-		for part in parts {
-			let time = match part {
-				1 => solver.part_one(self.debug, &mut buf),
-				2 => solver.part_two(self.debug, &mut buf),
-				p => solver.run_any(p, self.debug, &mut buf)?,
-			};
+		for (i, part) in parts.into_iter().enumerate() {
+			total_time += self.isolate_solver(day, &file, &mut solver, i)?;
+			let (time, _, stop, answer) = self.run_part(&mut *solver, day, part)?;
 			total_time += time;
+			if stop != Stop::Finished {
+				println!("d{day:02}p{part:02}: timed out, not saving its answer");
+				continue;
+			}
 
 			let part = part as usize - 1;
 			if part >= answer_vec.len() {
-				answer_vec.resize(part + 1, String::new().into());
+				answer_vec.resize(part + 1, Answer::None);
 			}
-			let saved = answer_vec[part].to_mut();
+			let saved = &mut answer_vec[part];
 
 			print!("d{day:02}p{:02}: ", part + 1);
 
-			if !saved.is_empty() {
-				if buf.eq(saved) {
+			if ans_file_name.is_none() {
+				println!("Answer is {answer:?}, not saved since it's for `--input`");
+			} else if !saved.is_none() {
+				if answer == *saved {
 					if self.test > 0 {
-						println!("Test {:02} answer is still {:?}", self.test, buf);
+						println!("Test {:02} answer is still {:?}", self.test, answer);
 					} else {
-						println!("Answer is still {buf:?}");
+						println!("Answer is still {answer:?}");
 					}
 				} else {
 					if self.test > 0 {
@@ -482,9 +1066,8 @@ impl Settings {
 					} else {
 						print!("Replacing main answer");
 					}
-					println!(" {saved:?} with {buf:?}");
+					println!(" {saved:?} with {answer:?}");
 				}
-				saved.clear();
 			} else {
 				print!("Saving ");
 				if self.test > 0 {
@@ -492,14 +1075,16 @@ impl Settings {
 				} else {
 					print!("main answer");
 				}
-				println!(" {buf:?}");
+				println!(" {answer:?}");
 			}
 
-			*saved += &buf;
-			buf.clear();
+			*saved = answer;
+			saved_any = true;
 		}
 
-		std::fs::write(ans_file_name, answer_vec.join("\n") + "\n")?;
+		if let (Some(path), true) = (ans_file_name, saved_any) {
+			std::fs::write(path, answer_lines(&answer_vec))?;
+		}
 
 		Ok(total_time)
 	}
@@ -507,12 +1092,17 @@ impl Settings {
 	fn validate(&mut self, day_parts: &[(u32, Vec<u32>)]) -> Res<Duration> {
 		let mut times = Duration::ZERO;
 		let mut incorrect = 0;
+		let mut cache = AnswerCache::load()?;
 
 		for &(day, ref parts) in day_parts {
-			let (t, i) = self.validate_day(day, parts)?;
+			if !self.runnable(day) {
+				continue;
+			}
+			let (t, i) = self.validate_day(&mut cache, day, parts)?;
 			times += t;
 			incorrect += i;
 		}
+		cache.save()?;
 
 		if incorrect == 0 {
 			println!("All answers were correct!");
@@ -522,27 +1112,82 @@ impl Settings {
 		}
 	}
 
-	fn validate_day(&mut self, day: u32, parts: &[u32]) -> Res<(Duration, u32)> {
+	fn validate_day(
+		&mut self,
+		cache: &mut AnswerCache,
+		day: u32,
+		parts: &[u32],
+	) -> Res<(Duration, u32)> {
 		let file = self.get_input(day)?;
 
-		let ans_file_name = answer_file_name(day, self.test);
-		let answers = if ans_file_name.exists() {
-			std::fs::read_to_string(&ans_file_name)?
-		} else {
-			debug_println!(
-				self.runner_debug,
-				1,
-				"Answer file {:?} missing, saving current answers",
-				ans_file_name
-			);
-			let t = self.save_day(day, parts)?;
-			return Ok((t, 0));
+		let ans_file_name = self.answer_path(day);
+		let embedded = self.embedded(day).filter(|_| self.answers.is_none());
+		// External answer files have to exist, since they are never saved to
+		let answers = match (embedded, &ans_file_name) {
+			// Embedded inputs without answers are only reported, never saved next to the binary
+			(Some(embedded), _) => embedded.answers.unwrap_or_default().to_string(),
+			(None, Some(path)) if path.exists() || self.answers.is_some() => {
+				std::fs::read_to_string(path)?
+			}
+			(None, Some(path)) => {
+				debug_println!(
+					self.runner_debug,
+					1,
+					"Answer file {:?} missing, saving current answers",
+					path
+				);
+				let t = self.save_day(day, parts)?;
+				return Ok((t, 0));
+			}
+			// An `--input` without `--answers` has nothing to compare to
+			(None, None) => String::new(),
 		};
-		let mut answer_vec = answers.lines().map(Cow::Borrowed).collect_vec();
 
-		let (mut total_time, mut solver) = day_to_solver(day, file, self.debug)?;
-		let mut buf = String::new();
-		let mut incorrect = 0;
+		let (mut total_time, mut incorrect, answers) =
+			self.validate_answers(cache, day, parts, file, &answers, None)?;
+
+		// Only the saved answers are written to, never `--answers` or the embedded ones
+		if let (Some(path), None, None) = (ans_file_name, &self.answers, embedded) {
+			std::fs::write(path, answers)?;
+		}
+
+		// The corpus is only checked alongside the main input
+		if self.input.is_none() && self.test == 0 {
+			for (name, input, answers) in corpus_files(day)? {
+				if !answers.exists() {
+					eprintln!(
+						"d{day:02}: corpus input {name:?} has no answer file {answers:?}, skipping"
+					);
+					continue;
+				}
+				let file = std::fs::read(input)?;
+				let file = self.normalize_input(day, &format!("corpus input {name:?}"), file);
+				let answers = std::fs::read_to_string(answers)?;
+				let (t, i, _) =
+					self.validate_answers(cache, day, parts, file, &answers, Some(&name))?;
+				total_time += t;
+				incorrect += i;
+			}
+		}
+
+		Ok((total_time, incorrect))
+	}
+
+	/// Runs `parts` on `file` and compares them to the lines of `answers`. Empty answers are
+	/// filled in with the current answer, except for corpus inputs, whose answer files are never
+	/// written. `corpus_name` is used in messages for corpus inputs.
+	///
+	/// Returns the time taken, the number of incorrect answers, and the updated answer file.
+	fn validate_answers(
+		&self,
+		cache: &mut AnswerCache,
+		day: u32,
+		parts: &[u32],
+		file: Vec<u8>,
+		answers: &str,
+		corpus_name: Option<&str>,
+	) -> Res<(Duration, u32, String)> {
+		let mut answer_vec = answers.lines().map(Answer::parse_line).collect_vec();
 
 		let parts = if parts.is_empty() {
 			vec![1, 2]
@@ -550,58 +1195,705 @@ impl Settings {
 			parts.to_vec()
 		};
 
-		for part in parts {
-			let time = match part {
-				1 => solver.part_one(self.debug, &mut buf),
-				2 => solver.part_two(self.debug, &mut buf),
-				p => solver.run_any(p, self.debug, &mut buf)?,
+		// The solver is only needed if the answers aren't cached
+		let cached = self.cached_answers(cache, day, &parts, &file);
+		let (mut total_time, mut solver) = match cached {
+			Some(_) => (Duration::ZERO, None),
+			None => {
+				let (time, solver) = self.initialize(day, file.clone())?;
+				(time, Some(solver))
+			}
+		};
+		let mut incorrect = 0;
+
+		// Which input this is, for the start and the middle of messages. `None` is the main input.
+		let (which, which_lower) = match corpus_name {
+			Some(name) => (
+				Some(format!("Corpus input {name:?}")),
+				Some(format!("corpus input {name:?}")),
+			),
+			None if self.test > 0 => (
+				Some(format!("Test {:02}", self.test)),
+				Some(format!("test {:02}", self.test)),
+			),
+			None => (None, None),
+		};
+
+		for (i, part) in parts.into_iter().enumerate() {
+			let answer = match (&cached, &mut solver) {
+				(Some(entries), _) => entries[i].answer.clone(),
+				(None, Some(solver)) => {
+					total_time += self.isolate_solver(day, &file, solver, i)?;
+					let (time, _, stop, answer) = self.run_part(&mut **solver, day, part)?;
+					total_time += time;
+					if stop != Stop::Finished {
+						println!("d{day:02}p{part:02}: timed out, not checking its answer");
+						continue;
+					}
+					self.cache_answer(cache, day, part, &file, &answer, time);
+					answer
+				}
+				(None, None) => unreachable!("solver is initialized when nothing is cached"),
 			};
-			total_time += time;
 
 			let part = part as usize - 1;
 			if part >= answer_vec.len() {
-				answer_vec.resize(part + 1, String::new().into());
+				answer_vec.resize(part + 1, Answer::None);
 			}
-			let saved = answer_vec[part].to_mut();
+			let saved = &mut answer_vec[part];
 
 			print!("d{day:02}p{:02}: ", part + 1);
 
-			if !saved.is_empty() {
-				if buf.eq(saved) {
-					if self.test > 0 {
-						println!("Test {:02} answer is correct: {:?}", self.test, buf);
+			if !saved.is_none() {
+				if answer == *saved {
+					if let Some(which) = &which {
+						println!("{which} answer is correct: {answer:?}");
 					} else {
-						println!("Answer is correct: {buf:?}");
+						println!("Answer is correct: {answer:?}");
 					}
 				} else {
-					if self.test > 0 {
-						print!("Test {:02} answer", self.test);
+					if let Some(which) = &which {
+						print!("{which} answer");
 					} else {
 						print!("main answer");
 					}
-					println!(" {buf:?} did not match saved answer {saved:?}");
+					println!(" {answer:?} did not match saved answer {saved:?}");
 					if self.exit_on_incorrect {
 						return Err(AocError::IncorrectAnswer);
 					}
 					incorrect += 1;
 				}
+			} else if let Some(name) = corpus_name {
+				println!(
+					"Corpus input {name:?} has no answer for this part, {answer:?} is unchecked"
+				);
+			} else if self.answer_path(day).is_none() {
+				println!("Answer is {answer:?}, there is no answer file to compare it to");
+			} else if self.answers.is_none() && self.embedded(day).is_some() {
+				println!("Answer is {answer:?}, no answers were embedded to compare it to");
+			} else if answer.is_none() {
+				println!("No answer to save");
 			} else {
 				print!("Saving ");
-				if self.test > 0 {
-					print!("test {:02} answer", self.test);
+				if let Some(which) = &which_lower {
+					print!("{which} answer");
 				} else {
 					print!("main answer");
 				}
-				println!(" {buf:?}");
-				saved.clear();
-				*saved += &buf;
+				println!(" {answer:?}");
+				*saved = answer;
 			}
-			buf.clear();
 		}
 
-		std::fs::write(ans_file_name, answer_vec.join("\n") + "\n")?;
+		Ok((total_time, incorrect, answer_lines(&answer_vec)))
+	}
 
-		Ok((total_time, incorrect))
+	fn determinism(&mut self, day_parts: &[(u32, Vec<u32>)]) -> Res<Duration> {
+		if !determinism::install_random_source() {
+			eprintln!("WARNING: couldn't control hasher seeds, a hash map was created too early");
+		}
+
+		let max_threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+		let base_seed = determinism::random_usize();
+		let mut total_time = Duration::ZERO;
+		let mut unstable = 0;
+
+		for &(day, _) in day_parts {
+			if !self.runnable(day) {
+				continue;
+			}
+
+			let file = self.get_input(day)?;
+			// Each run's answers along with the seed and thread count that produced them
+			let mut runs: Vec<([Answer; 2], usize, usize)> = Vec::with_capacity(self.runs);
+
+			for _ in 0..self.runs {
+				let seed = if self.random_seeds {
+					determinism::random_usize()
+				} else {
+					base_seed
+				};
+				let threads = if self.random_threads {
+					determinism::random_usize() % max_threads + 1
+				} else {
+					max_threads
+				};
+
+				let pool = rayon::ThreadPoolBuilder::new()
+					.num_threads(threads)
+					.build()
+					.map_err(|e| AocError::OtherError {
+						source: Box::new(e),
+					})?;
+
+				determinism::reset_hasher_seed(seed);
+				let (times, p1, p2) = pool.install(|| self.bench_once(day, file.clone()))?;
+				total_time += times.iter().sum::<Duration>();
+				runs.push(([p1, p2], seed, threads));
+			}
+
+			for part in 0..2 {
+				let first = &runs[0].0[part];
+				let differing = runs
+					.iter()
+					.filter(|(answers, _, _)| answers[part] != *first)
+					.collect_vec();
+
+				print!("d{day:02}p{:02}: ", part + 1);
+				if differing.is_empty() {
+					if !self.hide_answers {
+						println!("Stable over {} runs: {first:?}", runs.len());
+					} else {
+						println!("Stable over {} runs", runs.len());
+					}
+					continue;
+				}
+
+				unstable += 1;
+				println!(
+					"UNSTABLE, {} of {} runs differ from the first",
+					differing.len(),
+					runs.len()
+				);
+				for (answers, seed, threads) in &runs {
+					if !self.hide_answers {
+						println!(
+							"    seed {seed:#018x}, {threads:>3} threads: {:?}",
+							answers[part]
+						);
+					} else {
+						let matches = if answers[part] == *first {
+							"same"
+						} else {
+							"different"
+						};
+						println!("    seed {seed:#018x}, {threads:>3} threads: {matches}");
+					}
+				}
+			}
+		}
+
+		if unstable == 0 {
+			println!("All answers were stable!");
+			Ok(total_time)
+		} else {
+			Err(AocError::Nondeterministic(unstable))
+		}
+	}
+
+	fn new_days(&mut self, day_parts: &[(u32, Vec<u32>)]) -> Res<Duration> {
+		for &(day, _) in day_parts {
+			if !(1..=25).contains(&day) {
+				eprintln!("Day {day} not found, skipping");
+				continue;
+			}
+
+			let module = scaffold::create_module(day)?;
+			println!("Created {}", module.display());
+			scaffold::register(day)?;
+			println!("Registered day {day} in src/days.rs and Cargo.toml");
+
+			// This waits for the puzzle to release if it's close
+			self.get_input(day)?;
+			if !prompt(day).exists() {
+				self.get_prompt(day, &api_key()?)?;
+			}
+
+			let mut examples = 0;
+			for test in 1..=u8::MAX {
+				if !input_file_name(day, test).exists() {
+					break;
+				}
+				examples = test;
+				let answers = answer_file_name(day, test);
+				if !answers.exists() {
+					File::create(&answers)?;
+					println!(
+						"Created {}, fill in the answers from the prompt",
+						answers.display()
+					);
+				}
+			}
+			scaffold::add_example_tests(day, examples)?;
+			if examples > 0 {
+				println!(
+					"Added ignored tests for {examples} examples to {}",
+					module.display()
+				);
+			}
+		}
+
+		println!("Rebuild the runner to run the new days");
+		Ok(Duration::ZERO)
+	}
+
+	fn stats(&mut self) -> Res<Duration> {
+		let api_key = api_key()?;
+		let url = format!("https://adventofcode.com/{YEAR}/leaderboard/self");
+		eprintln!("Fetching {url}");
+		let req = self
+			.client()
+			.get(&url)
+			.set("cookie", &format!("session={api_key}"))
+			.call()?;
+		if !success(req.status()) {
+			return Err(AocError::StatsResponse {
+				status: req.status(),
+				response: req.into_string()?,
+			});
+		}
+		let page = req.into_string()?;
+		let stats = stats::parse_personal_stats(&page)?;
+
+		let medians = match &self.report {
+			Some(path) if path.with_extension("csv").exists() => {
+				report::read_medians(&path.with_extension("csv"))?
+			}
+			_ => Default::default(),
+		};
+
+		let table = stats::render_table(&stats, &medians);
+		print!("{table}");
+
+		let readme = std::fs::read_to_string(stats::README)?;
+		std::fs::write(stats::README, stats::update_readme(&readme, &table)?)?;
+		println!("Updated {}", stats::README);
+		Ok(Duration::ZERO)
+	}
+
+	fn leaderboard(&mut self, ids: &[u64]) -> Res<Duration> {
+		if ids.is_empty() {
+			return Err(AocError::NoLeaderboardId);
+		}
+
+		for &id in ids {
+			let cache = leaderboard::cache_file_name(id);
+			if leaderboard::cache_is_fresh(id) {
+				debug_println!(
+					self.runner_debug,
+					1,
+					"Using {cache:?}, it was fetched less than 15 minutes ago"
+				);
+			} else {
+				let api_key = api_key()?;
+				let url =
+					format!("https://adventofcode.com/{YEAR}/leaderboard/private/view/{id}.json");
+				eprintln!("Fetching {url}");
+				let req = self
+					.client()
+					.get(&url)
+					.set("cookie", &format!("session={api_key}"))
+					.call()?;
+				if !success(req.status()) {
+					return Err(AocError::LeaderboardResponse {
+						status: req.status(),
+						response: req.into_string()?,
+					});
+				}
+				let data = read_to_vec(req)?;
+
+				create_dir_all(cache.parent().unwrap())?;
+				if cache.exists() {
+					std::fs::rename(&cache, leaderboard::previous_file_name(id))?;
+				}
+				std::fs::write(&cache, data)?;
+			}
+
+			let board = leaderboard::parse(&std::fs::read(&cache)?)?;
+			let previous_path = leaderboard::previous_file_name(id);
+			let previous = if previous_path.exists() {
+				Some(leaderboard::parse(&std::fs::read(previous_path)?)?)
+			} else {
+				None
+			};
+
+			println!("Leaderboard {id}");
+			println!("{}", leaderboard::render(&board, previous.as_ref()));
+		}
+		Ok(Duration::ZERO)
+	}
+
+	fn countdown(&mut self, day_parts: &[(u32, Vec<u32>)]) -> Res<Duration> {
+		let days = if day_parts.is_empty() {
+			vec![release::next_unlock(YEAR as _).ok_or(AocError::AllReleased)?]
+		} else {
+			day_parts.iter().map(|&(day, _)| day).collect()
+		};
+
+		for day in days {
+			if !(1..=25).contains(&day) {
+				eprintln!("Day {day} not found, skipping");
+				continue;
+			}
+
+			loop {
+				if progress::interrupted() {
+					eprintln!();
+					return Err(AocError::Interrupted);
+				}
+				let remaining = time_until_input_is_released(day);
+				if remaining <= ChDuration::zero() {
+					break;
+				}
+				let seconds = remaining.num_seconds();
+				eprint!(
+					"\rDay {day} unlocks in {}:{:02}:{:02}:{:02}\x1b[K",
+					seconds / 86400,
+					seconds / 3600 % 24,
+					seconds / 60 % 60,
+					seconds % 60,
+				);
+				std::io::Write::flush(&mut std::io::stderr()).ok();
+				// Wake up right when the next second ticks over
+				let sleep = remaining - ChDuration::seconds(seconds);
+				let sleep = if sleep <= ChDuration::zero() {
+					ChDuration::seconds(1)
+				} else {
+					sleep
+				};
+				std::thread::sleep(sleep.to_std().unwrap_or_default());
+			}
+			eprintln!("\rDay {day} is unlocked!\x1b[K");
+
+			// This waits a few seconds after release before fetching
+			self.get_input(day)?;
+			println!("Fetched {}", input_file_name(day, 0).display());
+		}
+		Ok(Duration::ZERO)
+	}
+
+	fn clear_cache(&mut self, day_parts: &[(u32, Vec<u32>)]) -> Res<Duration> {
+		if day_parts.is_empty() {
+			match std::fs::remove_file(cache::CACHE_FILE) {
+				Ok(()) => println!("Removed {}", cache::CACHE_FILE),
+				Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+					println!("There is no answer cache")
+				}
+				Err(e) => return Err(e.into()),
+			}
+			return Ok(Duration::ZERO);
+		}
+
+		let mut cache = AnswerCache::load()?;
+		for &(day, _) in day_parts {
+			let removed = cache.remove_day(day);
+			println!("d{day:02}: removed {removed} cached answers");
+		}
+		cache.save()?;
+		Ok(Duration::ZERO)
+	}
+
+	fn bisect(&mut self, day_parts: &[(u32, Vec<u32>)]) -> Res<Duration> {
+		let (day, part) = bisect_target(day_parts)?;
+		let good = self.good.clone().ok_or(AocError::NoGoodCommit)?;
+		if !bisect::git(&["status", "--porcelain", "--untracked-files=no"])?.is_empty() {
+			return Err(AocError::DirtyWorkTree);
+		}
+
+		let original = bisect::current_ref()?;
+		// Resolved now, since `HEAD` names the good commit once that is checked out
+		let bad = bisect::git(&["rev-parse", "--verify", &self.bad])?;
+
+		// Whatever happens, go back to where we started
+		let result = bisect::copy_runner().and_then(|step| {
+			bisect::clear_log()?;
+			self.run_bisect(day, part, &good, &bad, &step)
+		});
+		bisect::restore(&original)?;
+		let first_bad = result?;
+
+		let show = |measurement: Option<bisect::Measurement>| match measurement {
+			Some(m) if self.hide_answers => format!("{:?}", m.time),
+			Some(m) => m.to_string(),
+			None => "not measured".to_string(),
+		};
+		let parent = bisect::git(&["rev-parse", &format!("{first_bad}^")]).ok();
+		println!(
+			"First bad commit: {}",
+			bisect::git(&["log", "-1", "--format=%h %s", &first_bad])?
+		);
+		println!(
+			"    before: {}",
+			show(parent.and_then(|p| bisect::logged(&p)))
+		);
+		println!("    after:  {}", show(bisect::logged(&first_bad)));
+		Ok(Duration::ZERO)
+	}
+
+	/// Measures the good commit, then runs `git bisect run` from it to the `bad` commit with a copy
+	/// of this runner. Returns the first bad commit.
+	fn run_bisect(
+		&self,
+		day: u32,
+		part: u32,
+		good: &str,
+		bad: &str,
+		step: &PathBuf,
+	) -> Res<String> {
+		bisect::git(&["checkout", "--quiet", "--detach", good])?;
+		eprintln!("Measuring good commit {good}");
+		let before = bisect::measure(day, part, self.test, self.runs)?
+			.ok_or_else(|| AocError::CannotMeasure(good.to_string()))?;
+		bisect::log(&before)?;
+		println!("{good}: {before}");
+		if self
+			.slower_than
+			.is_some_and(|ms| before.time.as_secs_f64() * 1000.0 > ms)
+		{
+			return Err(AocError::GoodCommitIsBad(good.to_string()));
+		}
+
+		bisect::git(&["bisect", "start", bad, good])?;
+		let mut command = std::process::Command::new("git");
+		command
+			.args(["bisect", "run"])
+			.arg(step)
+			.arg(format!("{day}.{part}"))
+			.args(["--mode", "bisect-step"])
+			.args(["--test", &self.test.to_string()])
+			.args(["--runs", &self.runs.to_string()]);
+		match self.slower_than {
+			Some(ms) => command.args(["--slower-than", &ms.to_string()]),
+			None => command.args(["--bisect-expect", &before.answer]),
+		};
+		if !command.status()?.success() {
+			return Err(AocError::Git {
+				args: "bisect run".to_string(),
+				stderr: "see the output above".to_string(),
+			});
+		}
+		bisect::git(&["rev-parse", "refs/bisect/bad"])
+	}
+
+	/// Runs at each commit of a bisect. Errors abort the bisect instead of marking the commit bad.
+	fn bisect_step(&mut self, day_parts: &[(u32, Vec<u32>)]) -> Res<Duration> {
+		let code = match self.bisect_step_code(day_parts) {
+			Ok(code) => code,
+			Err(e) => {
+				eprintln!("{e}");
+				bisect::ABORT
+			}
+		};
+		std::process::exit(code)
+	}
+
+	fn bisect_step_code(&self, day_parts: &[(u32, Vec<u32>)]) -> Res<i32> {
+		let (day, part) = bisect_target(day_parts)?;
+		let commit = bisect::git(&["log", "-1", "--format=%h"])?;
+		let Some(measurement) = bisect::measure(day, part, self.test, self.runs)? else {
+			println!("{commit}: couldn't build or run, skipping");
+			return Ok(bisect::SKIP);
+		};
+		bisect::log(&measurement)?;
+
+		let bad = match (self.slower_than, &self.bisect_expect) {
+			(Some(ms), _) => measurement.time.as_secs_f64() * 1000.0 > ms,
+			(None, Some(expected)) => measurement.answer != *expected,
+			(None, None) => return Err(AocError::NoGoodCommit),
+		};
+		println!(
+			"{commit}: {measurement} ({})",
+			if bad { "bad" } else { "good" }
+		);
+		Ok(if bad { bisect::BAD } else { bisect::GOOD })
+	}
+
+	fn interactive(&mut self) -> Res<Duration> {
+		tui::run(self.debug)?;
+		Ok(Duration::ZERO)
+	}
+
+	fn crosscheck(&mut self, day_parts: &[(u32, Vec<u32>)]) -> Res<Duration> {
+		let mut total_time = Duration::ZERO;
+		let mut differing = 0;
+
+		for &(day, ref parts) in day_parts {
+			if !(1..=25).contains(&day) {
+				eprintln!("Day {day} not found, skipping");
+				continue;
+			}
+			let rust = crate::days::is_compiled(day);
+			let externals = self
+				.externals
+				.iter()
+				.filter(|e| e.days.contains(&day))
+				.cloned()
+				.collect_vec();
+			if externals.len() + usize::from(rust) < 2 {
+				eprintln!("d{day:02}: fewer than two solvers, skipping");
+				continue;
+			}
+
+			let file = self.get_input(day)?;
+			let parts = if parts.is_empty() {
+				&[1, 2][..]
+			} else {
+				&parts[..]
+			};
+
+			// Each solver's name, answers, and total time
+			let mut results = Vec::new();
+			let rust_solver = rust.then_some(("rust", None));
+			let external_solvers = externals.iter().map(|e| (e.name.as_str(), Some(e)));
+			for (name, external) in rust_solver.into_iter().chain(external_solvers) {
+				let (mut time, mut solver) = match external {
+					Some(external) => external.initialize(day, &file)?,
+					None => facade::initialize(day, file.clone(), self.debug)?,
+				};
+				let mut answers = Vec::new();
+				for &part in parts {
+					let (t, _, _, answer) = self.run_part(&mut *solver, day, part)?;
+					time += t;
+					answers.push(answer);
+				}
+				total_time += time;
+				results.push((name, answers, time));
+			}
+
+			for (i, part) in parts.iter().enumerate() {
+				let first = &results[0].1[i];
+				print!("d{day:02}p{part:02}: ");
+				if results.iter().all(|(_, answers, _)| answers[i] == *first) {
+					if !self.hide_answers {
+						println!("All {} solvers agree: {first:?}", results.len());
+					} else {
+						println!("All {} solvers agree", results.len());
+					}
+					continue;
+				}
+
+				differing += 1;
+				println!("SOLVERS DISAGREE");
+				for (name, answers, _) in &results {
+					if !self.hide_answers {
+						println!("    {name:>10}: {:?}", answers[i]);
+					} else {
+						let matches = if answers[i] == *first {
+							"same as first"
+						} else {
+							"different"
+						};
+						println!("    {name:>10}: {matches}");
+					}
+				}
+			}
+			let times = results
+				.iter()
+				.map(|(name, _, time)| format!("{name} {}", readable_time(*time, 3)))
+				.join(", ");
+			println!("d{day:02} times: {times}\n");
+		}
+
+		if differing == 0 {
+			println!("All solvers agreed!");
+			Ok(total_time)
+		} else {
+			Err(AocError::SolversDisagree(differing))
+		}
+	}
+
+	#[cfg(feature = "hot-reload")]
+	fn hot(&mut self, day_parts: &[(u32, Vec<u32>)]) -> Res<Duration> {
+		let mut days = Vec::new();
+		for &(day, ref parts) in day_parts {
+			// The runner doesn't need the day compiled in, only the library does
+			if !(1..=25).contains(&day) {
+				eprintln!("Day {day} not found, skipping");
+				continue;
+			}
+			let parts = if parts.is_empty() {
+				vec![1, 2]
+			} else {
+				parts.clone()
+			};
+			days.push((day, parts, self.get_input(day)?));
+		}
+
+		let mut solver_time = Duration::ZERO;
+		for generation in 0.. {
+			let changed = hot::last_change();
+			for (day, parts, input) in &days {
+				solver_time += self.run_hot_day(*day, parts, input, generation)?;
+			}
+			println!("Watching src/ for changes, press Ctrl-C to stop");
+			if !hot::wait_for_change(changed) {
+				break;
+			}
+		}
+		Ok(solver_time)
+	}
+
+	/// Builds, loads, and runs one day for hot mode. Build failures and panics are printed and
+	/// don't stop the runner.
+	#[cfg(feature = "hot-reload")]
+	fn run_hot_day(&self, day: u32, parts: &[u32], input: &[u8], generation: u32) -> Res<Duration> {
+		let Some(path) = hot::build(day, generation)? else {
+			println!("d{day:02}: build failed\n");
+			return Ok(Duration::ZERO);
+		};
+		let lib = hot::HotDay::load(&path, day)?;
+		// Loaded libraries stay mapped, so the copy isn't needed anymore
+		std::fs::remove_file(&path).ok();
+
+		let Some((time, mut solver)) = lib.initialize(input, self.debug) else {
+			println!("d{day:02}: initialization failed\n");
+			return Ok(Duration::ZERO);
+		};
+		// Allocations happen in the library, which the runner's allocator can't see
+		print_times(day, 0, "", time, AllocStats::default());
+		let mut day_time = time;
+
+		let saved = self
+			.answer_path(day)
+			.and_then(|path| std::fs::read_to_string(path).ok())
+			.unwrap_or_default();
+		let saved = saved.lines().map(Answer::parse_line).collect_vec();
+
+		for &part in parts {
+			let (time, answer) = match solver.run_part(part, self.debug) {
+				Ok(res) => res,
+				Err(e) => {
+					// A solver that panicked can't run more parts
+					println!("d{day:02}p{part:02}: {e}");
+					break;
+				}
+			};
+			day_time += time;
+
+			let expected = (part as usize).checked_sub(1).and_then(|i| saved.get(i));
+			let check = match expected {
+				Some(expected) if expected.is_none() => String::new(),
+				Some(expected) if *expected == answer => " (correct)".to_string(),
+				Some(_) if self.hide_answers => " (incorrect)".to_string(),
+				Some(expected) => format!(" (expected {expected})"),
+				None => String::new(),
+			};
+			let answer = if self.hide_answers {
+				Answer::None
+			} else {
+				answer
+			};
+			print_times(
+				day,
+				part,
+				format!("{answer}{check}"),
+				time,
+				AllocStats::default(),
+			);
+		}
+		println!("d{day:02} total: {day_time:?}\n");
+		Ok(day_time)
+	}
+
+	fn serve(&mut self) -> Res<Duration> {
+		serve::Server {
+			normalize: self.normalize.clone(),
+			debug: self.debug,
+			bench_time: Duration::from_millis(self.bench_time),
+			bench_count: self.bench_count,
+		}
+		.run(self.port)?;
+		Ok(Duration::ZERO)
 	}
 
 	fn prompt(&mut self, day_parts: &[(u32, Vec<u32>)]) -> Result<Duration, AocError> {
@@ -647,8 +1939,24 @@ fn readable_time(duration: Duration, places: usize) -> String {
 	}
 }
 
-fn print_times<D: Display>(day: u32, part: u32, ans: D, time: Duration) {
-	println!("d{day:02}p{part:02}: ({time:?}) {ans}");
+/// Bisect mode works on exactly one part.
+fn bisect_target(day_parts: &[(u32, Vec<u32>)]) -> Res<(u32, u32)> {
+	match day_parts {
+		[(day, parts)] => match parts[..] {
+			[part] => Ok((*day, part)),
+			_ => Err(AocError::BisectTarget),
+		},
+		_ => Err(AocError::BisectTarget),
+	}
+}
+
+/// Formats answers as the lines of an answer file.
+fn answer_lines(answers: &[Answer]) -> String {
+	answers.iter().map(Answer::to_line).join("\n") + "\n"
+}
+
+fn print_times<D: Display>(day: u32, part: u32, ans: D, time: Duration, mem: AllocStats) {
+	println!("d{day:02}p{part:02}: ({time:?}{mem}) {ans}");
 }
 
 fn prompt(day: u32) -> PathBuf {
@@ -677,6 +1985,35 @@ fn input_file_name(day: u32, test: u8) -> PathBuf {
 	name
 }
 
+/// Lists the corpus inputs for `day`, which are the files `inputs/dayNN/corpus/NAME.txt`, along
+/// with their names and answer files `NAME.answer.txt`. Sorted by name.
+fn corpus_files(day: u32) -> Res<Vec<(String, PathBuf, PathBuf)>> {
+	let mut dir = input_base_name(day);
+	dir.push("corpus");
+	if !dir.is_dir() {
+		return Ok(Vec::new());
+	}
+
+	let mut files = Vec::new();
+	for entry in std::fs::read_dir(&dir)? {
+		let path = entry?.path();
+		let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+			continue;
+		};
+		let Some(name) = name.strip_suffix(".txt") else {
+			continue;
+		};
+		if name.ends_with(".answer") {
+			continue;
+		}
+		let name = name.to_string();
+		let answers = dir.join(format!("{name}.answer.txt"));
+		files.push((name, path, answers));
+	}
+	files.sort_unstable();
+	Ok(files)
+}
+
 fn input_base_name(day: u32) -> PathBuf {
 	PathBuf::from(format!("./inputs/day{day:02}"))
 }
@@ -720,173 +2057,67 @@ fn parse_day(word: &str) -> Res<Vec<(u32, Vec<u32>)>> {
 	})
 }
 
-fn day_to_solver(day: u32, file: Vec<u8>, dbg: u8) -> Res<(Duration, Box<dyn SolverSafe>)> {
-	use crate::days::*;
-	#[allow(clippy::zero_prefixed_literal)]
-	Ok(match day {
-		01 => {
-			let (time, solver) = time_fn(|| day01::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		02 => {
-			let (time, solver) = time_fn(|| day02::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		03 => {
-			let (time, solver) = time_fn(|| day03::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		04 => {
-			let (time, solver) = time_fn(|| day04::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		05 => {
-			let (time, solver) = time_fn(|| day05::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		06 => {
-			let (time, solver) = time_fn(|| day06::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		07 => {
-			let (time, solver) = time_fn(|| day07::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		08 => {
-			let (time, solver) = time_fn(|| day08::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		09 => {
-			let (time, solver) = time_fn(|| day09::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		10 => {
-			let (time, solver) = time_fn(|| day10::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		11 => {
-			let (time, solver) = time_fn(|| day11::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		12 => {
-			let (time, solver) = time_fn(|| day12::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		13 => {
-			let (time, solver) = time_fn(|| day13::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		14 => {
-			let (time, solver) = time_fn(|| day14::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		15 => {
-			let (time, solver) = time_fn(|| day15::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		16 => {
-			let (time, solver) = time_fn(|| day16::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		17 => {
-			let (time, solver) = time_fn(|| day17::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		18 => {
-			let (time, solver) = time_fn(|| day18::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		19 => {
-			let (time, solver) = time_fn(|| day19::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		20 => {
-			let (time, solver) = time_fn(|| day20::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		21 => {
-			let (time, solver) = time_fn(|| day21::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		22 => {
-			let (time, solver) = time_fn(|| day22::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		23 => {
-			let (time, solver) = time_fn(|| day23::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		24 => {
-			let (time, solver) = time_fn(|| day24::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		25 => {
-			let (time, solver) = time_fn(|| day25::Solution::initialize(file, dbg));
-			(time, Box::new(solver))
-		}
-		d => return Err(AocError::DayNotFound(d)),
-	})
+/// Returns whether `day` has a solution that can run, printing why it's skipped if it doesn't.
+fn runnable(day: u32) -> bool {
+	if !(1..=25).contains(&day) {
+		eprintln!("Day {day} not found, skipping");
+		false
+	} else if !crate::days::is_compiled(day) {
+		eprintln!("{}, skipping", AocError::DayCompiledOut(day));
+		false
+	} else {
+		true
+	}
 }
 
-fn day_to_bench(day: u32, file: Vec<u8>, dbg: u8) -> Res<(Duration, String, String)> {
+#[allow(clippy::zero_prefixed_literal)]
+#[cfg_attr(
+	not(any_day),
+	allow(unused_imports, unused_variables, unreachable_code)
+)]
+fn day_to_bench(day: u32, file: Vec<u8>, dbg: u8) -> Res<([Duration; 3], Answer, Answer)> {
 	use crate::days::*;
-	#[allow(clippy::zero_prefixed_literal)]
-	let res = match day {
-		01 => day01::Solution::run_both_string(file, dbg),
-		02 => day02::Solution::run_both_string(file, dbg),
-		03 => day03::Solution::run_both_string(file, dbg),
-		04 => day04::Solution::run_both_string(file, dbg),
-		05 => day05::Solution::run_both_string(file, dbg),
-		06 => day06::Solution::run_both_string(file, dbg),
-		07 => day07::Solution::run_both_string(file, dbg),
-		08 => day08::Solution::run_both_string(file, dbg),
-		09 => day09::Solution::run_both_string(file, dbg),
-		10 => day10::Solution::run_both_string(file, dbg),
-		11 => day11::Solution::run_both_string(file, dbg),
-		12 => day12::Solution::run_both_string(file, dbg),
-		13 => day13::Solution::run_both_string(file, dbg),
-		14 => day14::Solution::run_both_string(file, dbg),
-		15 => day15::Solution::run_both_string(file, dbg),
-		16 => day16::Solution::run_both_string(file, dbg),
-		17 => day17::Solution::run_both_string(file, dbg),
-		18 => day18::Solution::run_both_string(file, dbg),
-		19 => day19::Solution::run_both_string(file, dbg),
-		20 => day20::Solution::run_both_string(file, dbg),
-		21 => day21::Solution::run_both_string(file, dbg),
-		22 => day22::Solution::run_both_string(file, dbg),
-		23 => day23::Solution::run_both_string(file, dbg),
-		24 => day24::Solution::run_both_string(file, dbg),
-		25 => day25::Solution::run_both_string(file, dbg),
-		d => return Err(AocError::DayNotFound(d)),
-	};
+	macro_rules! bench_match {
+		($($num:literal => $day:ident if $feature:literal,)*) => {
+			match day {
+				$(
+					#[cfg(feature = $feature)]
+					$num => $day::Solution::run_both_timed(file, dbg),
+					#[cfg(not(feature = $feature))]
+					$num => return Err(AocError::DayCompiledOut($num)),
+				)*
+				d => return Err(AocError::DayNotFound(d)),
+			}
+		};
+	}
+	let res = with_days!(bench_match);
 	Ok(res)
 }
 
-/// Returns `None` if the input is released, otherwise returns the time until release. Returns
-/// `None` if the time cannot be determined.
-///
-/// # Warning
-///
-/// This is likely to break (by not allowing downloading of the puzzle for an extra hour) if the
-/// United States decides to remove time changes in favor of sticking to Daylight Saving Time,
-/// and Eric Wastl continues to keep AoC on US-East time. In such an event, change
-/// `ERIC_TIME_OFFSET` to `-4`.
-// Note: chrono is actually way more confusing than I thought. Idk if this is the correct way to
-// use it but it seems to work.
-fn time_until_input_is_released(day: u32) -> ChDuration {
-	const ERIC_TIME_OFFSET: i32 = -5;
-
-	let t = Utc::now().naive_utc();
-
-	let release = NaiveDate::from_ymd_opt(YEAR as _, 12, day)
-		.unwrap()
-		.and_hms_opt(0, 0, 0)
-		.unwrap()
-		.and_local_timezone(FixedOffset::east_opt(ERIC_TIME_OFFSET * 60 * 60).unwrap())
-		.unwrap()
-		.naive_utc();
+/// Hashes the source file of `day`'s module, which is embedded when the runner is built, together
+/// with the hash of the shared code from build.rs.
+#[allow(clippy::zero_prefixed_literal)]
+fn source_hash(day: u32) -> Option<u64> {
+	const SHARED: u64 = include!(concat!(env!("OUT_DIR"), "/shared_source_hash.rs"));
+	use crate::days::*;
+	macro_rules! source_match {
+		($($num:literal => $day:ident if $feature:literal,)*) => {
+			match day {
+				$($num => include_str!(concat!("days/", stringify!($day), ".rs")),)*
+				_ => return None,
+			}
+		};
+	}
+	let source = with_days!(source_match);
+	Some(cache::hash(
+		&[&SHARED.to_le_bytes(), source.as_bytes()].concat(),
+	))
+}
 
-	release - t
+/// Returns the time until the input for `day` is released. This is negative if it already has
+/// been.
+fn time_until_input_is_released(day: u32) -> ChDuration {
+	release::time_until_unlock(YEAR as _, day).expect("day should be in 1..=25")
 }
 
 /// Time a single function.
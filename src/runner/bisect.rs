@@ -0,0 +1,183 @@
+//! Helpers for `--mode bisect`, which drives `git bisect run`. At every commit git runs a copy of
+//! the current runner in `--mode bisect-step`, which builds that commit's runner, measures one
+//! part with it, and exits with the code `git bisect run` expects.
+
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::Duration;
+
+use crate::{AocError, Res};
+
+const BISECT_DIR: &str = "./target/bisect";
+/// One line per measured commit, `commit<TAB>nanoseconds<TAB>answer`.
+const LOG_FILE: &str = "./target/bisect/log.txt";
+
+/// Exit codes understood by `git bisect run`.
+pub(super) const GOOD: i32 = 0;
+pub(super) const BAD: i32 = 1;
+pub(super) const SKIP: i32 = 125;
+pub(super) const ABORT: i32 = 128;
+
+/// The fastest time and the answer of one part at one commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Measurement {
+	pub time: Duration,
+	pub answer: String,
+}
+
+impl Display for Measurement {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{:?} {:?}", self.time, self.answer)
+	}
+}
+
+/// Runs git and returns its trimmed stdout.
+pub(super) fn git(args: &[&str]) -> Res<String> {
+	let output = Command::new("git").args(args).output()?;
+	if !output.status.success() {
+		return Err(AocError::Git {
+			args: args.join(" "),
+			stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+		});
+	}
+	Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Returns the branch that is checked out, or the commit if `HEAD` is detached.
+pub(super) fn current_ref() -> Res<String> {
+	git(&["symbolic-ref", "--short", "-q", "HEAD"]).or_else(|_| git(&["rev-parse", "HEAD"]))
+}
+
+/// Ends any bisect and checks out `original` again.
+pub(super) fn restore(original: &str) -> Res<()> {
+	git(&["bisect", "reset", "--quiet"]).ok();
+	git(&["checkout", "--quiet", original])?;
+	Ok(())
+}
+
+/// Copies the running executable out of `target`, since every step rebuilds the runner there.
+pub(super) fn copy_runner() -> Res<PathBuf> {
+	std::fs::create_dir_all(BISECT_DIR)?;
+	let copy = PathBuf::from(BISECT_DIR).join(format!("runner{}", std::env::consts::EXE_SUFFIX));
+	std::fs::copy(std::env::current_exe()?, &copy)?;
+	Ok(copy)
+}
+
+/// Builds the checked out runner and runs `day.part` with it `runs` times. Returns `None` if the
+/// commit can't be measured, because it doesn't build or the runner fails.
+pub(super) fn measure(day: u32, part: u32, test: u8, runs: usize) -> Res<Option<Measurement>> {
+	let build = Command::new("cargo")
+		.args(["build", "--release", "--quiet"])
+		.status()?;
+	if !build.success() {
+		return Ok(None);
+	}
+
+	let exe = PathBuf::from(format!(
+		"./target/release/aoc2023{}",
+		std::env::consts::EXE_SUFFIX
+	));
+	let mut best: Option<Measurement> = None;
+	for _ in 0..runs.max(1) {
+		let mut command = Command::new(&exe);
+		command.arg(format!("{day}.{part}"));
+		if test > 0 {
+			command.args(["--test", &test.to_string()]);
+		}
+		let output = command.output()?;
+		if !output.status.success() {
+			return Ok(None);
+		}
+		let stdout = String::from_utf8_lossy(&output.stdout);
+		let Some(run) = parse_part_line(&stdout, day, part) else {
+			return Ok(None);
+		};
+		if best.as_ref().map_or(true, |b| run.time < b.time) {
+			best = Some(run);
+		}
+	}
+	Ok(best)
+}
+
+/// Finds the `dNNpNN: (time) answer` line that run mode prints for a part. The time can be
+/// followed by allocation stats, which are ignored.
+pub(super) fn parse_part_line(stdout: &str, day: u32, part: u32) -> Option<Measurement> {
+	let prefix = format!("d{day:02}p{part:02}: (");
+	let line = stdout.lines().find_map(|line| line.strip_prefix(&prefix))?;
+	let (inside, answer) = line.split_once(") ")?;
+	let time = inside.split(',').next()?;
+	Some(Measurement {
+		time: parse_duration(time)?,
+		answer: answer.to_string(),
+	})
+}
+
+/// Parses the `Debug` format of [`Duration`], like `1.5ms` or `12ns`.
+fn parse_duration(s: &str) -> Option<Duration> {
+	let (number, nanos) = [("ns", 1.0), ("µs", 1e3), ("ms", 1e6), ("s", 1e9)]
+		.into_iter()
+		.find_map(|(unit, nanos)| Some((s.strip_suffix(unit)?, nanos)))?;
+	let number: f64 = number.parse().ok()?;
+	Some(Duration::from_nanos((number * nanos).round() as u64))
+}
+
+pub(super) fn clear_log() -> Res<()> {
+	std::fs::create_dir_all(BISECT_DIR)?;
+	std::fs::write(LOG_FILE, "")?;
+	Ok(())
+}
+
+/// Records the measurement of the checked out commit.
+pub(super) fn log(measurement: &Measurement) -> Res<()> {
+	let commit = git(&["rev-parse", "HEAD"])?;
+	let mut log = std::fs::read_to_string(LOG_FILE).unwrap_or_default();
+	log += &format!(
+		"{commit}\t{}\t{}\n",
+		measurement.time.as_nanos(),
+		measurement.answer
+	);
+	std::fs::write(LOG_FILE, log)?;
+	Ok(())
+}
+
+/// Looks up the measurement of `commit` in the log.
+pub(super) fn logged(commit: &str) -> Option<Measurement> {
+	let log = std::fs::read_to_string(LOG_FILE).ok()?;
+	log.lines().rev().find_map(|line| {
+		let mut fields = line.splitn(3, '\t');
+		if fields.next()? != commit {
+			return None;
+		}
+		let time = Duration::from_nanos(fields.next()?.parse().ok()?);
+		let answer = fields.next()?.to_string();
+		Some(Measurement { time, answer })
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_durations() {
+		assert_eq!(parse_duration("12ns"), Some(Duration::from_nanos(12)));
+		assert_eq!(parse_duration("1.5ms"), Some(Duration::from_micros(1500)));
+		assert_eq!(parse_duration("2s"), Some(Duration::from_secs(2)));
+		assert_eq!(parse_duration("fast"), None);
+	}
+
+	#[test]
+	fn find_part_line() {
+		let stdout = "d03p00: (52.1µs) \nd03p01: (3ms, 12 allocs) 4361\nd03p02: (1ms) 467835\n";
+		assert_eq!(
+			parse_part_line(stdout, 3, 1),
+			Some(Measurement {
+				time: Duration::from_millis(3),
+				answer: "4361".to_string()
+			})
+		);
+		assert_eq!(parse_part_line(stdout, 3, 2).unwrap().answer, "467835");
+		assert_eq!(parse_part_line(stdout, 4, 1), None);
+	}
+}
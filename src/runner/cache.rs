@@ -0,0 +1,182 @@
+use std::path::Path;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Answer, AocError, Res};
+
+pub(super) const CACHE_FILE: &str = "./inputs/cache.json";
+
+/// Answers from earlier runs, keyed by day, part, debug level, and the hashes of the input and the
+/// code the day is built from. Stored as JSON in [`CACHE_FILE`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(super) struct AnswerCache {
+	entries: Vec<Entry>,
+	#[serde(skip)]
+	changed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct Entry {
+	pub day: u32,
+	pub part: u32,
+	pub input_hash: u64,
+	pub source_hash: u64,
+	/// The `--debug` level, since solvers can behave differently with it.
+	pub dbg: u8,
+	pub answer: Answer,
+	pub time_ns: u64,
+}
+
+impl Entry {
+	pub fn time(&self) -> Duration {
+		Duration::from_nanos(self.time_ns)
+	}
+}
+
+impl AnswerCache {
+	/// Reads the cache, or starts an empty one if there isn't a cache file yet. A cache that can't
+	/// be read, like one written before answers were typed, is also replaced by an empty one.
+	pub fn load() -> Res<Self> {
+		let path = Path::new(CACHE_FILE);
+		if !path.exists() {
+			return Ok(Self::default());
+		}
+		match serde_json::from_slice(&std::fs::read(path)?) {
+			Ok(cache) => Ok(cache),
+			Err(e) => {
+				eprintln!("Ignoring unreadable answer cache {CACHE_FILE}: {e}");
+				Ok(Self {
+					changed: true,
+					..Self::default()
+				})
+			}
+		}
+	}
+
+	/// Writes the cache if anything was inserted or removed since it was loaded. Runners with
+	/// embedded inputs never write it, since they are meant to run without the inputs directory.
+	pub fn save(&mut self) -> Res<()> {
+		if !self.changed || cfg!(feature = "embed-inputs") {
+			return Ok(());
+		}
+		let json = serde_json::to_vec_pretty(self).map_err(|e| AocError::OtherError {
+			source: Box::new(e),
+		})?;
+		std::fs::create_dir_all(Path::new(CACHE_FILE).parent().unwrap())?;
+		std::fs::write(CACHE_FILE, json)?;
+		self.changed = false;
+		Ok(())
+	}
+
+	pub fn get(
+		&self,
+		day: u32,
+		part: u32,
+		input_hash: u64,
+		source_hash: u64,
+		dbg: u8,
+	) -> Option<&Entry> {
+		self.entries.iter().find(|e| {
+			e.day == day
+				&& e.part == part
+				&& e.input_hash == input_hash
+				&& e.source_hash == source_hash
+				&& e.dbg == dbg
+		})
+	}
+
+	/// Returns the most recent entry for a part, whatever input and source it was for.
+	pub fn latest(&self, day: u32, part: u32) -> Option<&Entry> {
+		self.entries
+			.iter()
+			.rev()
+			.find(|e| e.day == day && e.part == part)
+	}
+
+	/// Adds `entry`, replacing any entry for the same part, input, and debug level. Entries from
+	/// older sources can never be hit again, so they are replaced too.
+	pub fn insert(&mut self, entry: Entry) {
+		let key = |e: &Entry| (e.day, e.part, e.input_hash, e.dbg);
+		self.entries.retain(|e| key(e) != key(&entry));
+		self.entries.push(entry);
+		self.changed = true;
+	}
+
+	/// Removes every entry for `day`. Returns how many were removed.
+	pub fn remove_day(&mut self, day: u32) -> usize {
+		let before = self.entries.len();
+		self.entries.retain(|e| e.day != day);
+		let removed = before - self.entries.len();
+		self.changed |= removed > 0;
+		removed
+	}
+}
+
+/// 64-bit FNV-1a. The cache outlives the binary, so this can't use a hasher that is seeded or
+/// allowed to change between Rust versions.
+pub(super) fn hash(bytes: &[u8]) -> u64 {
+	bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &b| {
+		(hash ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn entry(part: u32, source_hash: u64, answer: &str) -> Entry {
+		Entry {
+			day: 1,
+			part,
+			input_hash: 7,
+			source_hash,
+			dbg: 0,
+			answer: answer.into(),
+			time_ns: 0,
+		}
+	}
+
+	#[test]
+	fn fnv_test_vectors() {
+		assert_eq!(hash(b""), 0xcbf2_9ce4_8422_2325);
+		assert_eq!(hash(b"a"), 0xaf63_dc4c_8601_ec8c);
+	}
+
+	#[test]
+	fn new_source_replaces_entry() {
+		let mut cache = AnswerCache::default();
+		cache.insert(entry(1, 1, "old"));
+		cache.insert(entry(2, 1, "other part"));
+		cache.insert(entry(1, 2, "new"));
+		assert_eq!(cache.get(1, 1, 7, 1, 0), None);
+		assert_eq!(
+			cache.get(1, 1, 7, 2, 0).unwrap().answer,
+			Answer::from("new")
+		);
+		assert_eq!(
+			cache.get(1, 2, 7, 1, 0).unwrap().answer,
+			Answer::from("other part")
+		);
+		assert_eq!(cache.remove_day(1), 2);
+	}
+
+	#[test]
+	fn debug_level_is_part_of_the_key() {
+		let mut cache = AnswerCache::default();
+		cache.insert(entry(1, 1, "quiet"));
+		assert_eq!(cache.get(1, 1, 7, 1, 1), None);
+		cache.insert(Entry {
+			dbg: 1,
+			..entry(1, 1, "debug")
+		});
+		assert_eq!(
+			cache.get(1, 1, 7, 1, 0).unwrap().answer,
+			Answer::from("quiet")
+		);
+		assert_eq!(
+			cache.get(1, 1, 7, 1, 1).unwrap().answer,
+			Answer::from("debug")
+		);
+	}
+}
@@ -0,0 +1,39 @@
+use std::collections::hash_map::RandomState as StdRandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Once;
+
+use ahash::random_state::{set_random_source, RandomSource};
+
+/// Counter that every new `ahash` hasher takes its seed from while the runner's source is
+/// installed. Resetting it before a run makes that run's hash maps iterate in the same order.
+static HASHER_SEED: AtomicUsize = AtomicUsize::new(0);
+
+struct RunnerRandomSource;
+
+impl RandomSource for RunnerRandomSource {
+	fn gen_hasher_seed(&self) -> usize {
+		HASHER_SEED.fetch_add(0x9e37_79b9, Ordering::Relaxed)
+	}
+}
+
+/// Makes `ahash` take its seeds from [`reset_hasher_seed`]. This has to happen before the first
+/// hash map is created, otherwise `ahash` keeps its default source.
+pub(super) fn install_random_source() -> bool {
+	static INSTALL: Once = Once::new();
+	let mut installed = true;
+	INSTALL.call_once(|| installed = set_random_source(RunnerRandomSource).is_ok());
+	installed
+}
+
+/// Sets the seed that the next hashers are derived from.
+pub(super) fn reset_hasher_seed(seed: usize) {
+	HASHER_SEED.store(seed, Ordering::Relaxed);
+}
+
+/// Makes a new random number without pulling in a rng crate.
+pub(super) fn random_usize() -> usize {
+	let mut hasher = StdRandomState::new().build_hasher();
+	hasher.write_u64(0);
+	hasher.finish() as usize
+}
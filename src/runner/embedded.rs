@@ -0,0 +1,31 @@
+//! Inputs and answers built into the binary with the `embed-inputs` feature, so it can benchmark
+//! and validate on a machine without the inputs directory. `build.rs` generates the table from
+//! every `inputs/dayNN/input.txt` and `answer.txt` that exists when the runner is built.
+
+/// A day's real input and its answer file.
+pub(super) struct Embedded {
+	pub input: &'static [u8],
+	pub answers: Option<&'static str>,
+}
+
+/// Indexed by day minus one.
+#[cfg(feature = "embed-inputs")]
+static EMBEDDED: [Option<Embedded>; 25] = include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs"));
+#[cfg(not(feature = "embed-inputs"))]
+static EMBEDDED: [Option<Embedded>; 25] = [const { None }; 25];
+
+/// Returns the embedded input and answers for `day`, if it has any.
+pub(super) fn get(day: u32) -> Option<&'static Embedded> {
+	EMBEDDED.get(day.checked_sub(1)? as usize)?.as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn days_out_of_range() {
+		assert!(get(0).is_none());
+		assert!(get(26).is_none());
+	}
+}
@@ -0,0 +1,143 @@
+//! Solvers written in other languages, declared in [`CONFIG_FILE`]. Each one is a command that
+//! reads the input on stdin and prints the answer to each part on its own line, in order.
+//!
+//! ```json
+//! [
+//!     { "name": "python", "days": [1, 5], "command": ["python3", "python/day{day}.py"] },
+//!     { "name": "go", "days": [5], "command": ["go", "run", "./go/day05"] }
+//! ]
+//! ```
+//!
+//! `{day}` in the command is replaced with the two-digit day. The whole process is timed as
+//! initialization, since the parts can't be timed on their own.
+
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use super::time_fn;
+use crate::solution::SolverSafe;
+use crate::{Answer, AocError, Context, Res};
+
+pub(super) const CONFIG_FILE: &str = "./external_solvers.json";
+
+/// A solver command from [`CONFIG_FILE`].
+#[derive(Debug, Clone, Deserialize)]
+pub(super) struct External {
+	pub name: String,
+	pub days: Vec<u32>,
+	pub command: Vec<String>,
+}
+
+/// Reads [`CONFIG_FILE`], or returns no solvers if it doesn't exist.
+pub(super) fn load() -> Res<Vec<External>> {
+	let path = Path::new(CONFIG_FILE);
+	if !path.exists() {
+		return Ok(Vec::new());
+	}
+	serde_json::from_slice(&std::fs::read(path)?).map_err(|e| AocError::OtherError {
+		source: Box::new(e),
+	})
+}
+
+impl External {
+	/// Runs the command for `day` on `input`. Returns how long it took, and a solver that gives
+	/// back the answers it printed.
+	pub fn initialize(&self, day: u32, input: &[u8]) -> Res<(Duration, Box<dyn SolverSafe>)> {
+		let error = |reason: String| AocError::ExternalSolver {
+			name: self.name.clone(),
+			reason,
+		};
+		let [program, args @ ..] = &self.command[..] else {
+			return Err(error("the command is empty".to_string()));
+		};
+		let day = format!("{day:02}");
+
+		let (time, output) = time_fn(|| {
+			let mut child = Command::new(program)
+				.args(args.iter().map(|arg| arg.replace("{day}", &day)))
+				.stdin(Stdio::piped())
+				.stdout(Stdio::piped())
+				.spawn()?;
+			let mut stdin = child.stdin.take().unwrap();
+			std::thread::scope(|s| {
+				// Written from another thread so a solver that prints before reading all of its
+				// input can't block. Dropping stdin closes it, so the solver sees the end.
+				s.spawn(move || stdin.write_all(input).ok());
+				child.wait_with_output()
+			})
+		});
+		let output = output.map_err(|e| error(e.to_string()))?;
+		if !output.status.success() {
+			return Err(error(format!("it exited with {}", output.status)));
+		}
+
+		let stdout = String::from_utf8(output.stdout).map_err(|_| AocError::NonUtf8InSolution)?;
+		let answers = stdout
+			.lines()
+			.map(|line| Answer::parse_line(line.trim_end()))
+			.collect();
+		Ok((time, Box::new(ExternalSolver { answers })))
+	}
+}
+
+/// The answers printed by an external solver.
+struct ExternalSolver {
+	answers: Vec<Answer>,
+}
+
+impl ExternalSolver {
+	fn answer(&self, part: u32) -> Res<(Duration, Answer)> {
+		let answer = (part as usize)
+			.checked_sub(1)
+			.and_then(|i| self.answers.get(i))
+			.ok_or(AocError::PartNotFound)?;
+		Ok((Duration::ZERO, answer.clone()))
+	}
+}
+
+impl SolverSafe for ExternalSolver {
+	fn part_one(&mut self, _: u8, _: &Context) -> (Duration, Answer) {
+		self.answer(1).unwrap_or_default()
+	}
+
+	fn part_two(&mut self, _: u8, _: &Context) -> (Duration, Answer) {
+		self.answer(2).unwrap_or_default()
+	}
+
+	fn run_any(&mut self, part: u32, _: u8) -> Res<(Duration, Answer)> {
+		self.answer(part)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn reads_answers_from_stdout() {
+		let external = External {
+			name: "shell".to_string(),
+			days: vec![1],
+			command: vec![
+				"sh".into(),
+				"-c".into(),
+				"read a; echo $a; echo day{day}".into(),
+			],
+		};
+		let (_, mut solver) = external.initialize(1, b"3\n4\n").unwrap();
+		let ctx = Context::new();
+		assert_eq!(solver.part_one(0, &ctx).1, Answer::Integer(3));
+		assert_eq!(solver.part_two(0, &ctx).1, Answer::from("day01"));
+		assert!(matches!(solver.run_any(3, 0), Err(AocError::PartNotFound)));
+
+		let failing = External {
+			command: vec!["false".into()],
+			..external
+		};
+		assert!(failing.initialize(1, b"").is_err());
+	}
+}
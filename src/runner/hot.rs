@@ -0,0 +1,200 @@
+//! Hot mode. Each day is built on its own as a dynamic library by the `hot_day` crate and loaded
+//! with `dlopen`, then built and loaded again whenever something in `src/` changes. Only the
+//! library crate with that one day in it is rebuilt, and the runner keeps its inputs in between.
+
+use std::ffi::CStr;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use libloading::Library;
+
+use super::progress::interrupted;
+use crate::hot::{self, symbols, Handle};
+use crate::{Answer, AocError, Res};
+
+const HOT_DIR: &str = "./target/hot";
+const WATCH_DIR: &str = "./src";
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Builds the library for `day` and copies it to a path that only this load uses. A library can't
+/// always be unloaded, and loading the same path again would then give back the old one. Returns
+/// `None` if the build failed, after cargo has printed why.
+pub(super) fn build(day: u32, generation: u32) -> Res<Option<PathBuf>> {
+	let mut command = Command::new("cargo");
+	command.args(["build", "--quiet", "-p", "hot_day", "--features"]);
+	command.arg(format!("day{day:02}"));
+	// Match the runner's profile, so timings are comparable
+	let profile = if cfg!(debug_assertions) {
+		"debug"
+	} else {
+		command.arg("--release");
+		"release"
+	};
+	if !command.status()?.success() {
+		return Ok(None);
+	}
+
+	let file_name = |name: &str| {
+		format!(
+			"{}{name}{}",
+			std::env::consts::DLL_PREFIX,
+			std::env::consts::DLL_SUFFIX
+		)
+	};
+	let built = Path::new("./target")
+		.join(profile)
+		.join(file_name("hot_day"));
+	std::fs::create_dir_all(HOT_DIR)?;
+	let copy = Path::new(HOT_DIR).join(file_name(&format!("day{day:02}_{generation}")));
+	std::fs::copy(built, &copy)?;
+	Ok(Some(copy))
+}
+
+/// A loaded day library.
+pub(super) struct HotDay {
+	fns: Fns,
+	lib: Arc<Library>,
+}
+
+#[derive(Clone, Copy)]
+struct Fns {
+	initialize: hot::InitializeFn,
+	run_part: hot::RunPartFn,
+	free_solver: hot::FreeSolverFn,
+	free_string: hot::FreeStringFn,
+}
+
+impl HotDay {
+	/// Loads a library from [`build`] and checks that it was built for `day` with this runner's
+	/// ABI.
+	pub fn load(path: &Path, day: u32) -> Res<Self> {
+		let error = |reason: String| AocError::HotLoad {
+			path: path.display().to_string(),
+			reason,
+		};
+		// SAFETY: the library is built from this repository by `build`, and its functions have
+		// the signatures in `crate::hot`, which is checked with the ABI version.
+		unsafe {
+			let lib = Library::new(path).map_err(|e| error(e.to_string()))?;
+
+			let version = symbol::<hot::AbiVersionFn>(&lib, symbols::ABI_VERSION).map_err(error)?();
+			if version != hot::ABI_VERSION {
+				return Err(error(format!(
+					"ABI version {version} doesn't match the runner's {}",
+					hot::ABI_VERSION
+				)));
+			}
+			let lib_day = symbol::<hot::DayFn>(&lib, symbols::DAY).map_err(error)?();
+			if lib_day != day {
+				return Err(error(format!("built for day {lib_day} instead")));
+			}
+
+			let fns = Fns {
+				initialize: symbol(&lib, symbols::INITIALIZE).map_err(error)?,
+				run_part: symbol(&lib, symbols::RUN_PART).map_err(error)?,
+				free_solver: symbol(&lib, symbols::FREE_SOLVER).map_err(error)?,
+				free_string: symbol(&lib, symbols::FREE_STRING).map_err(error)?,
+			};
+			Ok(Self {
+				fns,
+				lib: Arc::new(lib),
+			})
+		}
+	}
+
+	/// Initializes a solver with `input`. Returns `None` if initializing failed or panicked, which
+	/// the library has already printed.
+	pub fn initialize(&self, input: &[u8], dbg: u8) -> Option<(Duration, HotSolver)> {
+		let mut time_ns = 0;
+		// SAFETY: `input` is valid for its length, and `time_ns` is writable.
+		let handle =
+			unsafe { (self.fns.initialize)(input.as_ptr(), input.len(), dbg, &mut time_ns) };
+		(!handle.is_null()).then(|| {
+			let solver = HotSolver {
+				handle,
+				fns: self.fns,
+				_lib: self.lib.clone(),
+			};
+			(Duration::from_nanos(time_ns), solver)
+		})
+	}
+}
+
+/// Looks up a function in `lib`.
+///
+/// # Safety
+/// `T` has to be the type of the function called `name`.
+unsafe fn symbol<T: Copy>(lib: &Library, name: &[u8]) -> Result<T, String> {
+	lib.get::<T>(name)
+		.map(|f| *f)
+		.map_err(|e| format!("{}: {e}", String::from_utf8_lossy(&name[..name.len() - 1])))
+}
+
+/// A solver that lives in a [`HotDay`] library. It keeps the library loaded until it is dropped.
+pub(super) struct HotSolver {
+	handle: *mut Handle,
+	fns: Fns,
+	_lib: Arc<Library>,
+}
+
+impl HotSolver {
+	/// Runs `part`. A part that panics or fails gives [`AocError::HotPart`], and the solver can't
+	/// be used again after a panic.
+	pub fn run_part(&mut self, part: u32, dbg: u8) -> Res<(Duration, Answer)> {
+		let mut time_ns = 0;
+		// SAFETY: the handle is live until `self` is dropped, and the returned string is copied
+		// before it is freed by the library that made it.
+		let json = unsafe {
+			let string = (self.fns.run_part)(self.handle, part, dbg, &mut time_ns);
+			let json = CStr::from_ptr(string).to_string_lossy().into_owned();
+			(self.fns.free_string)(string);
+			json
+		};
+		let res: Result<Answer, String> = serde_json::from_str(&json)
+			.map_err(|e| AocError::HotPart(format!("couldn't read the answer {json:?}: {e}")))?;
+		let answer = res.map_err(AocError::HotPart)?;
+		Ok((Duration::from_nanos(time_ns), answer))
+	}
+}
+
+impl Drop for HotSolver {
+	fn drop(&mut self) {
+		// SAFETY: the handle came from `initialize` and is only freed here.
+		unsafe { (self.fns.free_solver)(self.handle) }
+	}
+}
+
+/// Returns the last time anything in `src/` was modified.
+pub(super) fn last_change() -> SystemTime {
+	fn newest(dir: &Path) -> SystemTime {
+		let Ok(entries) = std::fs::read_dir(dir) else {
+			return SystemTime::UNIX_EPOCH;
+		};
+		entries
+			.flatten()
+			.map(|entry| match entry.file_type() {
+				Ok(t) if t.is_dir() => newest(&entry.path()),
+				_ => entry
+					.metadata()
+					.and_then(|m| m.modified())
+					.unwrap_or(SystemTime::UNIX_EPOCH),
+			})
+			.max()
+			.unwrap_or(SystemTime::UNIX_EPOCH)
+	}
+	newest(Path::new(WATCH_DIR))
+}
+
+/// Waits until something in `src/` changes after `since`. Returns false if Ctrl-C was pressed
+/// instead.
+pub(super) fn wait_for_change(since: SystemTime) -> bool {
+	while !interrupted() {
+		std::thread::sleep(POLL_INTERVAL);
+		if last_change() > since {
+			return true;
+		}
+	}
+	false
+}
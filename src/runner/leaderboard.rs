@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use serde::Deserialize;
+
+use crate::release::unlock_time;
+use crate::{Res, YEAR};
+
+/// The leaderboard API asks that each leaderboard is fetched at most once every 15 minutes.
+pub(super) const REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// The parts of the private leaderboard JSON that we use.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(super) struct Leaderboard {
+	pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(super) struct Member {
+	pub id: u64,
+	pub name: Option<String>,
+	pub stars: u32,
+	pub local_score: u32,
+	/// Day to part to star.
+	#[serde(default)]
+	pub completion_day_level: BTreeMap<u32, BTreeMap<u32, Star>>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub(super) struct Star {
+	pub get_star_ts: i64,
+}
+
+impl Member {
+	fn display_name(&self) -> String {
+		match &self.name {
+			Some(name) => name.clone(),
+			None => format!("(anonymous user #{})", self.id),
+		}
+	}
+}
+
+pub(super) fn cache_file_name(id: u64) -> PathBuf {
+	PathBuf::from(format!("./inputs/leaderboard/{id}.json"))
+}
+
+/// The cache from before the latest fetch, used to show what changed.
+pub(super) fn previous_file_name(id: u64) -> PathBuf {
+	PathBuf::from(format!("./inputs/leaderboard/{id}.previous.json"))
+}
+
+/// Returns true if the cached leaderboard is recent enough to use instead of fetching.
+pub(super) fn cache_is_fresh(id: u64) -> bool {
+	std::fs::metadata(cache_file_name(id))
+		.and_then(|m| m.modified())
+		.is_ok_and(|modified| {
+			SystemTime::now()
+				.duration_since(modified)
+				.is_ok_and(|age| age < REFRESH_INTERVAL)
+		})
+}
+
+pub(super) fn parse(json: &[u8]) -> Res<Leaderboard> {
+	serde_json::from_slice(json).map_err(|e| crate::AocError::OtherError {
+		source: Box::new(e),
+	})
+}
+
+/// Renders the standings, with changes since `previous`, followed by each member's star times
+/// relative to the puzzle release.
+pub(super) fn render(board: &Leaderboard, previous: Option<&Leaderboard>) -> String {
+	let mut members: Vec<&Member> = board.members.values().collect();
+	members.sort_by(|a, b| {
+		b.local_score
+			.cmp(&a.local_score)
+			.then(b.stars.cmp(&a.stars))
+	});
+
+	let mut out = String::new();
+	for (rank, member) in members.iter().enumerate() {
+		write!(
+			out,
+			"{:>3}) {:<24} {:>5} points {:>3} stars",
+			rank + 1,
+			member.display_name(),
+			member.local_score,
+			member.stars,
+		)
+		.unwrap();
+
+		let old = previous.and_then(|p| p.members.get(&member.id.to_string()));
+		match old {
+			Some(old) => {
+				let score = member.local_score as i64 - old.local_score as i64;
+				let stars = member.stars as i64 - old.stars as i64;
+				if score != 0 || stars != 0 {
+					write!(out, "  ({score:+} points, {stars:+} stars)").unwrap();
+				}
+			}
+			None if previous.is_some() => out += "  (new)",
+			None => (),
+		}
+		out += "\n";
+	}
+
+	out += "\nStar times after release\n";
+	for member in members {
+		if member.completion_day_level.is_empty() {
+			continue;
+		}
+		writeln!(out, "{}", member.display_name()).unwrap();
+		for (&day, parts) in &member.completion_day_level {
+			let Some(release) = unlock_time(YEAR as _, day) else {
+				continue;
+			};
+			let release = release.timestamp();
+			write!(out, "    d{day:02}").unwrap();
+			for part in 1..=2 {
+				match parts.get(&part) {
+					Some(star) => write!(out, " {:>10}", since_release(star.get_star_ts - release)),
+					None => write!(out, " {:>10}", "-"),
+				}
+				.unwrap();
+			}
+			out += "\n";
+		}
+	}
+	out
+}
+
+/// Formats seconds like the leaderboard does, as `hh:mm:ss`, letting hours go past 24.
+fn since_release(seconds: i64) -> String {
+	let seconds = seconds.max(0);
+	format!(
+		"{:02}:{:02}:{:02}",
+		seconds / 3600,
+		seconds / 60 % 60,
+		seconds % 60
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	// Day 1 of 2023 released at 1701406800
+	const BOARD: &str = r#"{"event":"2023","owner_id":1,"members":{
+		"1":{"id":1,"name":"ferris","stars":2,"local_score":10,"global_score":0,"last_star_ts":1701407700,
+			"completion_day_level":{"1":{"1":{"get_star_ts":1701407100,"star_index":0},"2":{"get_star_ts":1701407700,"star_index":1}}}},
+		"2":{"id":2,"name":null,"stars":1,"local_score":4,"global_score":0,"last_star_ts":1701410400,
+			"completion_day_level":{"1":{"1":{"get_star_ts":1701410400,"star_index":2}}}}
+	}}"#;
+	const PREVIOUS: &str = r#"{"event":"2023","owner_id":1,"members":{
+		"1":{"id":1,"name":"ferris","stars":1,"local_score":5,"global_score":0,"last_star_ts":1701407100,
+			"completion_day_level":{"1":{"1":{"get_star_ts":1701407100,"star_index":0}}}}
+	}}"#;
+
+	#[test]
+	fn parse_members() {
+		let board = parse(BOARD.as_bytes()).unwrap();
+		assert_eq!(board.members.len(), 2);
+		let anonymous = &board.members["2"];
+		assert_eq!(anonymous.display_name(), "(anonymous user #2)");
+		assert_eq!(
+			anonymous.completion_day_level[&1][&1].get_star_ts,
+			1701410400
+		);
+		assert!(parse(b"not json").is_err());
+	}
+
+	#[test]
+	fn render_changes() {
+		let board = parse(BOARD.as_bytes()).unwrap();
+		let previous = parse(PREVIOUS.as_bytes()).unwrap();
+		let out = render(&board, Some(&previous));
+		let lines: Vec<&str> = out.lines().collect();
+		assert!(lines[0].starts_with("  1) ferris"));
+		assert!(lines[0].ends_with("(+5 points, +1 stars)"));
+		assert!(lines[1].starts_with("  2) (anonymous user #2)"));
+		assert!(lines[1].ends_with("(new)"));
+		assert!(out.contains("    d01   00:05:00   00:15:00\n"));
+		assert!(out.contains("    d01   01:00:00          -\n"));
+	}
+
+	#[test]
+	fn render_without_previous() {
+		let board = parse(BOARD.as_bytes()).unwrap();
+		let out = render(&board, None);
+		assert!(!out.contains("(new)"));
+		assert!(!out.contains("points,"));
+	}
+}
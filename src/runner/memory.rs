@@ -0,0 +1,132 @@
+//! Allocation counting for the runner. The counting allocator is only installed with the
+//! `count-alloc` feature, and without it [`measure`] reports nothing.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+	static UNCOUNTED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Wraps the system allocator and keeps running totals of allocations.
+#[cfg_attr(not(feature = "count-alloc"), allow(dead_code))]
+pub struct CountingAllocator;
+
+#[cfg(feature = "count-alloc")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		let ptr = System.alloc(layout);
+		if !ptr.is_null() {
+			record_alloc(layout.size());
+		}
+		ptr
+	}
+
+	unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+		let ptr = System.alloc_zeroed(layout);
+		if !ptr.is_null() {
+			record_alloc(layout.size());
+		}
+		ptr
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		System.dealloc(ptr, layout);
+		LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+	}
+
+	unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+		let new_ptr = System.realloc(ptr, layout, new_size);
+		if !new_ptr.is_null() {
+			LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+			record_alloc(new_size);
+		}
+		new_ptr
+	}
+}
+
+#[cfg_attr(not(feature = "count-alloc"), allow(dead_code))]
+fn record_alloc(size: usize) {
+	// The thread local is gone while the thread is exiting, and those allocations are counted
+	if !UNCOUNTED.try_with(Cell::get).unwrap_or(false) {
+		ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+		ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+	}
+	let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+	PEAK_LIVE_BYTES.fetch_max(live, Ordering::Relaxed);
+}
+
+/// Stops counting the allocations and bytes of the current thread, for runner threads like the
+/// progress watcher that run alongside a measured function. Live bytes are still tracked, since
+/// memory can be freed on another thread than the one that allocated it.
+pub fn stop_counting_thread() {
+	UNCOUNTED.with(|uncounted| uncounted.set(true));
+}
+
+/// Allocation behavior of a measured function. `peak` is the most bytes that were live at once,
+/// not counting what was already live before the function started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AllocStats {
+	pub enabled: bool,
+	pub count: usize,
+	pub bytes: usize,
+	pub peak: usize,
+}
+
+/// Measures allocations made while running a single function, like
+/// [`time_fn`](super::time_fn). Allocations from other threads running at the same time are
+/// counted too, unless they called [`stop_counting_thread`].
+pub fn measure<F: FnOnce() -> T, T>(f: F) -> (AllocStats, T) {
+	if !cfg!(feature = "count-alloc") {
+		return (AllocStats::default(), f());
+	}
+
+	let count = ALLOCATIONS.load(Ordering::Relaxed);
+	let bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+	let live = LIVE_BYTES.load(Ordering::Relaxed);
+	PEAK_LIVE_BYTES.store(live, Ordering::Relaxed);
+
+	let t = f();
+
+	let stats = AllocStats {
+		enabled: true,
+		count: ALLOCATIONS.load(Ordering::Relaxed) - count,
+		bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes,
+		peak: PEAK_LIVE_BYTES.load(Ordering::Relaxed).saturating_sub(live),
+	};
+	(stats, t)
+}
+
+/// Writes nothing when allocation counting is disabled, so it can be put directly after a time.
+impl Display for AllocStats {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		if !self.enabled {
+			return Ok(());
+		}
+		write!(
+			f,
+			", {} allocs, {} total, {} peak",
+			self.count,
+			readable_bytes(self.bytes),
+			readable_bytes(self.peak),
+		)
+	}
+}
+
+pub fn readable_bytes(bytes: usize) -> String {
+	match bytes {
+		0..=1023 => format!("{bytes}B"),
+		1024..=1_048_575 => format!("{:.1}KiB", bytes as f32 / 1024.0),
+		_ => format!("{:.1}MiB", bytes as f32 / 1024.0 / 1024.0),
+	}
+}
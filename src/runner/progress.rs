@@ -0,0 +1,122 @@
+use std::io::{stderr, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+use super::{memory, readable_time};
+use crate::Context;
+
+/// Set by the Ctrl-C handler. A second Ctrl-C exits immediately.
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// How often the watcher thread wakes up to check on the solver.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Progress bars are only drawn for parts that take at least this long.
+const SHOW_AFTER: Duration = Duration::from_millis(500);
+const BAR_WIDTH: usize = 30;
+
+/// Why a watched solver stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Stop {
+	Finished,
+	TimedOut,
+	Interrupted,
+}
+
+/// Installs a Ctrl-C handler that asks the running solver to stop. Only the first call does
+/// anything.
+pub(super) fn install_interrupt_handler() {
+	static INSTALL: Once = Once::new();
+	INSTALL.call_once(|| {
+		let res = ctrlc::set_handler(|| {
+			if INTERRUPTED.swap(true, Ordering::Relaxed) {
+				std::process::exit(130);
+			}
+		});
+		if let Err(e) = res {
+			eprintln!("Couldn't install Ctrl-C handler: {e}");
+		}
+	});
+}
+
+/// Returns true if Ctrl-C has been pressed.
+pub(super) fn interrupted() -> bool {
+	INTERRUPTED.load(Ordering::Relaxed)
+}
+
+/// Runs `f` on this thread while another thread draws a progress bar from `ctx` on stderr. The
+/// context gets cancelled if `timeout` passes or Ctrl-C is pressed.
+pub(super) fn watch<F, T>(ctx: &Context, timeout: Option<Duration>, f: F) -> (T, Stop)
+where
+	F: FnOnce() -> T,
+{
+	let finished = AtomicBool::new(false);
+	let start = Instant::now();
+
+	std::thread::scope(|s| {
+		let watcher = s.spawn(|| {
+			// Drawing allocates, which shouldn't be counted against the part being measured
+			memory::stop_counting_thread();
+			let mut stop = Stop::Finished;
+			let mut drawn = false;
+			while !finished.load(Ordering::Relaxed) {
+				std::thread::park_timeout(POLL_INTERVAL);
+				let elapsed = start.elapsed();
+
+				if stop == Stop::Finished {
+					if interrupted() {
+						stop = Stop::Interrupted;
+						ctx.cancel();
+					} else if timeout.is_some_and(|t| elapsed >= t) {
+						stop = Stop::TimedOut;
+						ctx.cancel();
+					}
+				}
+
+				let (done, total) = ctx.get_progress();
+				if elapsed >= SHOW_AFTER && total > 0 && !finished.load(Ordering::Relaxed) {
+					eprint!("\r{}\x1b[K", progress_bar(done, total, elapsed));
+					stderr().flush().ok();
+					drawn = true;
+				}
+			}
+			if drawn {
+				eprint!("\r\x1b[K");
+			}
+			stop
+		});
+
+		let t = f();
+		finished.store(true, Ordering::Relaxed);
+		watcher.thread().unpark();
+		(t, watcher.join().unwrap())
+	})
+}
+
+/// Describes how far along a solver got, for diagnostics after it was stopped.
+pub(super) fn describe(ctx: &Context) -> String {
+	match ctx.get_progress() {
+		(_, 0) => "no progress reported".to_string(),
+		(done, total) => format!(
+			"{done}/{total} ({:.1}%)",
+			done as f64 / total as f64 * 100.0
+		),
+	}
+}
+
+fn progress_bar(done: u64, total: u64, elapsed: Duration) -> String {
+	let fraction = (done as f64 / total as f64).clamp(0.0, 1.0);
+	let filled = (fraction * BAR_WIDTH as f64) as usize;
+	let eta = if done > 0 {
+		let remaining = elapsed.as_secs_f64() * (1.0 - fraction) / fraction;
+		readable_time(Duration::from_secs_f64(remaining), 1)
+	} else {
+		"?".to_string()
+	};
+	format!(
+		"[{}{}] {:>5.1}% ETA {eta}",
+		"#".repeat(filled),
+		"-".repeat(BAR_WIDTH - filled),
+		fraction * 100.0,
+	)
+}
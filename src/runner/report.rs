@@ -0,0 +1,236 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::readable_time;
+use crate::helpers::HashMap;
+use crate::{AocError, Res};
+
+const CSV_HEADER: &str = "day,runs,median_ns,mean_ns,min_ns,max_ns,\
+	initialize_median_ns,part_one_median_ns,part_two_median_ns";
+
+/// All timings collected for one day in bench mode.
+#[derive(Debug, Clone)]
+pub(super) struct DayBench {
+	day: u32,
+	runs: usize,
+	median: Duration,
+	mean: Duration,
+	min: Duration,
+	max: Duration,
+	/// Medians of initialize, part one, and part two
+	parts: [Duration; 3],
+}
+
+impl DayBench {
+	/// Summarizes the samples, which are initialize, part one, and part two times for each run.
+	pub(super) fn new(day: u32, samples: Vec<[Duration; 3]>) -> Self {
+		let mut totals: Vec<Duration> = samples.iter().map(|s| s.iter().sum()).collect();
+		totals.sort_unstable();
+		let runs = totals.len().max(1);
+
+		let parts = std::array::from_fn(|i| {
+			let mut part: Vec<Duration> = samples.iter().map(|s| s[i]).collect();
+			part.sort_unstable();
+			median(&part)
+		});
+
+		Self {
+			day,
+			runs: totals.len(),
+			median: median(&totals),
+			mean: totals.iter().sum::<Duration>() / runs as u32,
+			min: totals.first().copied().unwrap_or_default(),
+			max: totals.last().copied().unwrap_or_default(),
+			parts,
+		}
+	}
+}
+
+fn median(sorted: &[Duration]) -> Duration {
+	sorted.get(sorted.len() / 2).copied().unwrap_or_default()
+}
+
+/// Reads the median time of each day from a CSV file written by [`write_report`].
+pub(super) fn read_medians(path: &Path) -> Res<HashMap<u32, Duration>> {
+	let csv = std::fs::read_to_string(path)?;
+	let mut medians = HashMap::new();
+	for line in csv.lines().skip(1) {
+		let mut fields = line.split(',');
+		let day = fields.next().and_then(|d| d.parse().ok());
+		let median = fields.nth(1).and_then(|m| m.parse().ok());
+		let (Some(day), Some(median)) = (day, median) else {
+			return Err(AocError::BadBaseline {
+				path: path.display().to_string(),
+				line: line.to_string(),
+			});
+		};
+		medians.insert(day, Duration::from_nanos(median));
+	}
+	Ok(medians)
+}
+
+/// Writes `path.csv` and `path.html`, and returns both paths.
+pub(super) fn write_report(
+	path: &Path,
+	benches: &[DayBench],
+	baseline: Option<&HashMap<u32, Duration>>,
+) -> Res<(PathBuf, PathBuf)> {
+	let csv_path = path.with_extension("csv");
+	let html_path = path.with_extension("html");
+	std::fs::write(&csv_path, csv(benches, baseline)?)?;
+	std::fs::write(&html_path, html(benches, baseline)?)?;
+	Ok((csv_path, html_path))
+}
+
+/// Percent change from the baseline median, if the baseline has this day.
+fn change(bench: &DayBench, baseline: Option<&HashMap<u32, Duration>>) -> Option<(Duration, f64)> {
+	let &old = baseline?.get(&bench.day)?;
+	let change = (bench.median.as_secs_f64() / old.as_secs_f64() - 1.0) * 100.0;
+	Some((old, change))
+}
+
+fn csv(benches: &[DayBench], baseline: Option<&HashMap<u32, Duration>>) -> Res<String> {
+	let mut out = String::from(CSV_HEADER);
+	if baseline.is_some() {
+		out += ",baseline_median_ns,change_percent";
+	}
+	out += "\n";
+
+	for bench in benches {
+		write!(
+			out,
+			"{},{},{},{},{},{},{},{},{}",
+			bench.day,
+			bench.runs,
+			bench.median.as_nanos(),
+			bench.mean.as_nanos(),
+			bench.min.as_nanos(),
+			bench.max.as_nanos(),
+			bench.parts[0].as_nanos(),
+			bench.parts[1].as_nanos(),
+			bench.parts[2].as_nanos(),
+		)?;
+		if baseline.is_some() {
+			match change(bench, baseline) {
+				Some((old, change)) => write!(out, ",{},{change:.2}", old.as_nanos())?,
+				None => out += ",,",
+			}
+		}
+		out += "\n";
+	}
+	Ok(out)
+}
+
+const STYLE: &str = "\
+body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; margin-bottom: 2em; }
+th, td { padding: 0.25em 0.75em; text-align: right; border-bottom: 1px solid #ddd; }
+.chart td { text-align: left; }
+.bar { display: flex; height: 1em; min-width: 1px; }
+.bar span { height: 100%; }
+.p0 { background: #9ab; } .p1 { background: #4a8; } .p2 { background: #d84; }
+.faster { color: #080; } .slower { color: #c00; }
+";
+
+fn html(benches: &[DayBench], baseline: Option<&HashMap<u32, Duration>>) -> Res<String> {
+	let mut out = String::new();
+	writeln!(
+		out,
+		"<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+		<title>AoC {} benchmark</title>\n<style>\n{STYLE}</style>\n</head>\n<body>",
+		crate::YEAR
+	)?;
+	writeln!(out, "<h1>Advent of Code {} benchmark</h1>", crate::YEAR)?;
+
+	// Summary table
+	out += "<h2>Days</h2>\n<table>\n<tr><th>Day</th><th>Runs</th><th>Median</th><th>Mean</th>\
+		<th>Min</th><th>Max</th>";
+	if baseline.is_some() {
+		out += "<th>Baseline</th><th>Change</th>";
+	}
+	out += "</tr>\n";
+	for bench in benches {
+		write!(
+			out,
+			"<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td>",
+			bench.day,
+			bench.runs,
+			readable_time(bench.median, 3),
+			readable_time(bench.mean, 3),
+			readable_time(bench.min, 3),
+			readable_time(bench.max, 3),
+		)?;
+		if baseline.is_some() {
+			match change(bench, baseline) {
+				Some((old, change)) => {
+					let class = if change <= 0.0 { "faster" } else { "slower" };
+					write!(
+						out,
+						"<td>{}</td><td class=\"{class}\">{change:+.1}%</td>",
+						readable_time(old, 3)
+					)?;
+				}
+				None => out += "<td></td><td></td>",
+			}
+		}
+		out += "</tr>\n";
+	}
+	let total: Duration = benches.iter().map(|b| b.median).sum();
+	writeln!(
+		out,
+		"<tr><th>All</th><td></td><th>{}</th></tr>\n</table>",
+		readable_time(total, 3)
+	)?;
+
+	// Bar chart of medians, split into initialize, part one, and part two
+	out += "<h2>Median time</h2>\n<p><span class=\"p0\">&nbsp;&nbsp;</span> initialize \
+		<span class=\"p1\">&nbsp;&nbsp;</span> part one \
+		<span class=\"p2\">&nbsp;&nbsp;</span> part two</p>\n<table class=\"chart\">\n";
+	let longest = benches
+		.iter()
+		.map(|b| b.parts.iter().sum::<Duration>())
+		.max()
+		.unwrap_or_default()
+		.as_secs_f64()
+		.max(f64::MIN_POSITIVE);
+	for bench in benches {
+		let parts_total: Duration = bench.parts.iter().sum();
+		write!(
+			out,
+			"<tr><td>Day {}</td><td style=\"width: 40em\"><div class=\"bar\" style=\"width: {:.2}%\">",
+			bench.day,
+			parts_total.as_secs_f64() / longest * 100.0,
+		)?;
+		for (i, part) in bench.parts.iter().enumerate() {
+			let share = part.as_secs_f64() / parts_total.as_secs_f64().max(f64::MIN_POSITIVE);
+			write!(
+				out,
+				"<span class=\"p{i}\" style=\"width: {:.2}%\"></span>",
+				share * 100.0
+			)?;
+		}
+		writeln!(
+			out,
+			"</div></td><td>{}</td></tr>",
+			readable_time(bench.median, 3)
+		)?;
+	}
+	out += "</table>\n";
+
+	// Part breakdown
+	out += "<h2>Parts</h2>\n<table>\n<tr><th>Day</th><th>Initialize</th><th>Part one</th>\
+		<th>Part two</th></tr>\n";
+	for bench in benches {
+		writeln!(
+			out,
+			"<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+			bench.day,
+			readable_time(bench.parts[0], 3),
+			readable_time(bench.parts[1], 3),
+			readable_time(bench.parts[2], 3),
+		)?;
+	}
+	out += "</table>\n</body>\n</html>\n";
+	Ok(out)
+}
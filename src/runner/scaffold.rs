@@ -0,0 +1,238 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use crate::{AocError, Res};
+
+/// Template for new days. `{{day}}` is replaced with the two-digit day number.
+const TEMPLATE: &str = "./templates/day.rs";
+const DAYS: &str = "./src/days.rs";
+const MANIFEST: &str = "./Cargo.toml";
+const HOT_MANIFEST: &str = "./hot_day/Cargo.toml";
+
+fn module_file_name(day: u32) -> PathBuf {
+	PathBuf::from(format!("./src/days/day{day:02}.rs"))
+}
+
+/// Writes `src/days/dayNN.rs` from the template. Fails if the module already exists.
+pub(super) fn create_module(day: u32) -> Res<PathBuf> {
+	let path = module_file_name(day);
+	if path.exists() {
+		return Err(AocError::DayExists(day));
+	}
+	let template = std::fs::read_to_string(TEMPLATE)?;
+	std::fs::write(&path, template.replace("{{day}}", &format!("{day:02}")))?;
+	Ok(path)
+}
+
+/// Adds the day's `mod` declaration and its line in `with_days` to `src/days.rs`, and its cargo
+/// feature to `Cargo.toml` and `hot_day/Cargo.toml`. Every list is kept sorted, and anything that
+/// is already there is left alone.
+pub(super) fn register(day: u32) -> Res<()> {
+	edit_lines(DAYS, day, register_module)?;
+	edit_lines(MANIFEST, day, register_feature)?;
+	edit_lines(HOT_MANIFEST, day, register_hot_feature)
+}
+
+/// Runs `edit` on the lines of `path` and writes them back.
+fn edit_lines<F>(path: &'static str, day: u32, edit: F) -> Res<()>
+where
+	F: FnOnce(&mut Vec<String>, u32) -> Option<()>,
+{
+	let source = std::fs::read_to_string(path)?;
+	let mut lines: Vec<String> = source.lines().map(String::from).collect();
+	edit(&mut lines, day).ok_or(AocError::CannotRegister { day, file: path })?;
+	std::fs::write(path, lines.join("\n") + "\n")?;
+	Ok(())
+}
+
+fn register_module(lines: &mut Vec<String>, day: u32) -> Option<()> {
+	let module_line = format!("pub mod day{day:02};");
+	insert_sorted(lines, day, module_line, |line| {
+		line.strip_prefix("pub mod day")?
+			.strip_suffix(';')?
+			.parse()
+			.ok()
+	})?;
+
+	let arm_line = format!("\t\t\t{day:02} => day{day:02} if \"day{day:02}\",");
+	insert_sorted(lines, day, arm_line, |line| {
+		let (num, module) = line.trim().split_once(" => ")?;
+		module.starts_with("day").then_some(())?;
+		num.parse().ok()
+	})
+}
+
+/// Adds `dayNN = []` to the features, and the feature to `default`.
+fn register_feature(lines: &mut Vec<String>, day: u32) -> Option<()> {
+	insert_sorted(lines, day, format!("day{day:02} = []"), |line| {
+		line.strip_prefix("day")?
+			.strip_suffix(" = []")?
+			.parse()
+			.ok()
+	})?;
+	insert_sorted(lines, day, format!("\t\"day{day:02}\","), |line| {
+		line.strip_prefix("\t\"day")?
+			.strip_suffix("\",")?
+			.parse()
+			.ok()
+	})
+}
+
+/// Adds `dayNN = ["aoc2023/dayNN"]` to the hot reloading crate's features.
+fn register_hot_feature(lines: &mut Vec<String>, day: u32) -> Option<()> {
+	let line = format!("day{day:02} = [\"aoc2023/day{day:02}\"]");
+	insert_sorted(lines, day, line, |line| {
+		let (feature, _) = line.split_once(" = [\"aoc2023/")?;
+		feature.strip_prefix("day")?.parse().ok()
+	})
+}
+
+/// Inserts `new` among the lines that `day_of` recognizes, before the first one with a later
+/// day. Does nothing if `new` is already there. Returns `None` if no lines are recognized.
+fn insert_sorted<F>(lines: &mut Vec<String>, day: u32, new: String, day_of: F) -> Option<()>
+where
+	F: Fn(&str) -> Option<u32>,
+{
+	if lines.contains(&new) {
+		return Some(());
+	}
+	let days: Vec<(usize, u32)> = lines
+		.iter()
+		.enumerate()
+		.filter_map(|(i, line)| Some((i, day_of(line)?)))
+		.collect();
+	let &(last, _) = days.last()?;
+	let index = days
+		.iter()
+		.find(|&&(_, d)| d > day)
+		.map_or(last + 1, |&(i, _)| i);
+	lines.insert(index, new);
+	Some(())
+}
+
+/// Adds a test module to the day's module with a test for each of the `examples` extracted from
+/// the prompt. The tests are ignored until their answers are filled in.
+pub(super) fn add_example_tests(day: u32, examples: u8) -> Res<()> {
+	if examples == 0 {
+		return Ok(());
+	}
+	let path = module_file_name(day);
+	let mut module = std::fs::read_to_string(&path)?;
+	module += &example_tests(day, examples);
+	std::fs::write(path, module)?;
+	Ok(())
+}
+
+fn example_tests(day: u32, examples: u8) -> String {
+	let mut tests = format!(
+		"
+#[cfg(test)]
+mod tests {{
+	use crate::Answer;
+
+	/// Runs `part` on one of the examples from the prompt.
+	fn example(test: u8, part: u32) -> Answer {{
+		let input = std::fs::read(format!(\"inputs/day{day:02}/input{{test:02}}.txt\")).unwrap();
+		crate::solve({day}, part, &input).unwrap()
+	}}
+"
+	);
+	for test in 1..=examples {
+		write!(
+			tests,
+			"
+	#[test]
+	#[ignore = \"fill in the answers from the prompt\"]
+	fn example_{test:02}() {{
+		assert_eq!(example({test}, 1), Answer::None);
+		assert_eq!(example({test}, 2), Answer::None);
+	}}
+"
+		)
+		.unwrap();
+	}
+	tests + "}\n"
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn insert_between() {
+		let mut lines = vec![
+			"pub mod day01;".to_string(),
+			"pub mod day03;".to_string(),
+			"".to_string(),
+		];
+		insert_sorted(&mut lines, 2, "pub mod day02;".to_string(), |line| {
+			line.strip_prefix("pub mod day")?
+				.strip_suffix(';')?
+				.parse()
+				.ok()
+		})
+		.unwrap();
+		assert_eq!(
+			lines,
+			["pub mod day01;", "pub mod day02;", "pub mod day03;", ""]
+		);
+	}
+
+	#[test]
+	fn insert_last() {
+		let mut lines = vec![
+			"\t\t\t01 => day01 if \"day01\",".to_string(),
+			"\t\t}".to_string(),
+		];
+		let new = "\t\t\t02 => day02 if \"day02\",".to_string();
+		insert_sorted(&mut lines, 2, new, |line| {
+			line.trim().split_once(" => ")?.0.parse().ok()
+		})
+		.unwrap();
+		assert_eq!(
+			lines,
+			[
+				"\t\t\t01 => day01 if \"day01\",",
+				"\t\t\t02 => day02 if \"day02\",",
+				"\t\t}"
+			]
+		);
+	}
+
+	#[test]
+	fn features_are_added_once() {
+		let manifest =
+			"[features]\ndefault = [\n\t\"day01\",\n\t\"day03\",\n]\nday01 = []\nday03 = []\n";
+		let mut lines: Vec<String> = manifest.lines().map(String::from).collect();
+		register_feature(&mut lines, 2).unwrap();
+		register_feature(&mut lines, 2).unwrap();
+		assert_eq!(
+			lines,
+			[
+				"[features]",
+				"default = [",
+				"\t\"day01\",",
+				"\t\"day02\",",
+				"\t\"day03\",",
+				"]",
+				"day01 = []",
+				"day02 = []",
+				"day03 = []",
+			]
+		);
+
+		let mut lines = vec!["day01 = [\"aoc2023/day01\"]".to_string()];
+		register_hot_feature(&mut lines, 2).unwrap();
+		assert_eq!(lines[1], "day02 = [\"aoc2023/day02\"]");
+	}
+
+	#[test]
+	fn example_test_stubs() {
+		let tests = example_tests(7, 2);
+		assert!(tests.contains("inputs/day07/input{test:02}.txt"));
+		assert!(tests.contains("crate::solve(7, part, &input)"));
+		assert!(tests.contains("fn example_01()"));
+		assert!(tests.contains("fn example_02()"));
+		assert!(!tests.contains("fn example_03()"));
+	}
+}
@@ -0,0 +1,516 @@
+//! Serve mode, a small HTTP server so editor plugins and dashboards can run days without parsing
+//! the runner's output. It only listens on localhost, and every endpoint answers with JSON.
+//!
+//! - `GET /days` lists the compiled days and whether each has a saved input and answer file.
+//! - `POST /solve/DAY?parts=1,2` solves the input in the request body.
+//! - `GET /validate/DAY?test=N` runs the saved input and compares it to the saved answers.
+//! - `POST /bench/DAY?count=N` benchmarks the input in the request body, or the saved input if
+//!   the body is empty. Progress is streamed as one JSON object per line, ending with a `done` or
+//!   `error` event.
+//!
+//! Days are run through [`facade`], and each request gets its own thread.
+
+use std::io::Read;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tiny_http::{Header, Method, Request, Response, StatusCode};
+
+use super::{answer_file_name, input_file_name};
+use crate::normalize::{normalize, Normalization};
+use crate::{facade, Answer, AocError, Context, Res};
+
+/// How often benchmarks send a progress event.
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+const WARMUP_RUNS: usize = 10;
+
+/// Settings that requests are handled with, copied from the runner's settings.
+#[derive(Debug, Clone)]
+pub(super) struct Server {
+	pub normalize: Vec<Normalization>,
+	pub debug: u8,
+	pub bench_time: Duration,
+	pub bench_count: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Route {
+	Days,
+	Solve(u32),
+	Validate(u32),
+	Bench(u32),
+}
+
+/// An error response.
+#[derive(Debug)]
+struct Failure {
+	status: u16,
+	message: String,
+}
+
+impl Failure {
+	fn bad_request(message: impl Into<String>) -> Self {
+		Self {
+			status: 400,
+			message: message.into(),
+		}
+	}
+}
+
+impl From<AocError> for Failure {
+	fn from(e: AocError) -> Self {
+		let status = match e {
+			AocError::DayNotFound(_) | AocError::DayCompiledOut(_) | AocError::PartNotFound => 404,
+			_ => 500,
+		};
+		Self {
+			status,
+			message: e.to_string(),
+		}
+	}
+}
+
+impl From<std::io::Error> for Failure {
+	fn from(e: std::io::Error) -> Self {
+		AocError::from(e).into()
+	}
+}
+
+#[derive(Debug, Serialize)]
+struct DayInfo {
+	day: u32,
+	input: bool,
+	answers: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct Solved {
+	day: u32,
+	init_ns: u64,
+	parts: Vec<PartResult>,
+}
+
+#[derive(Debug, Serialize)]
+struct PartResult {
+	part: u32,
+	answer: Answer,
+	time_ns: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct Validated {
+	day: u32,
+	test: u8,
+	/// Whether every part with a saved answer was correct.
+	correct: bool,
+	parts: Vec<PartCheck>,
+}
+
+#[derive(Debug, Serialize)]
+struct PartCheck {
+	part: u32,
+	answer: Answer,
+	expected: Answer,
+	/// Missing when there is no saved answer to compare to.
+	correct: Option<bool>,
+	time_ns: u64,
+}
+
+/// A line of a benchmark stream. Times are in nanoseconds, and `[init, part one, part two]` where
+/// there are three of them.
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum BenchEvent {
+	Progress {
+		runs: usize,
+		elapsed_ns: u64,
+	},
+	Done {
+		runs: usize,
+		avg_ns: [u64; 3],
+		min_ns: [u64; 3],
+		answers: [Answer; 2],
+	},
+	Error {
+		error: String,
+	},
+}
+
+impl Server {
+	/// Handles requests until the process is stopped.
+	pub fn run(self, port: u16) -> Res<()> {
+		let server = tiny_http::Server::http(("127.0.0.1", port))
+			.map_err(|source| AocError::OtherError { source })?;
+		println!("Listening on http://127.0.0.1:{port}");
+
+		std::thread::scope(|s| {
+			for request in server.incoming_requests() {
+				let this = &self;
+				s.spawn(move || this.handle(request));
+			}
+		});
+		Ok(())
+	}
+
+	fn handle(&self, mut request: Request) {
+		if self.debug > 0 {
+			eprintln!("{} {}", request.method(), request.url());
+		}
+		let url = request.url().to_string();
+		let (path, query) = url.split_once('?').unwrap_or((&url, ""));
+
+		let res = match route(request.method(), path) {
+			Some(Route::Days) => json(&days()),
+			Some(Route::Solve(day)) => read_body(&mut request)
+				.and_then(|input| self.solve(day, input, query))
+				.and_then(|solved| json(&solved)),
+			Some(Route::Validate(day)) => self.validate(day, query).and_then(|v| json(&v)),
+			Some(Route::Bench(day)) => match read_body(&mut request) {
+				Ok(input) => {
+					let stream = self.bench(day, input, query);
+					let res =
+						Response::new(StatusCode(200), vec![json_header()], stream, None, None);
+					respond(request, res);
+					return;
+				}
+				Err(e) => Err(e),
+			},
+			None => Err(Failure {
+				status: 404,
+				message: format!("No endpoint for {} {path}", request.method()),
+			}),
+		};
+
+		match res {
+			Ok(body) => respond(
+				request,
+				Response::from_string(body).with_header(json_header()),
+			),
+			Err(failure) => {
+				let body = serde_json::json!({ "error": failure.message }).to_string();
+				let res = Response::from_string(body)
+					.with_status_code(failure.status)
+					.with_header(json_header());
+				respond(request, res);
+			}
+		}
+	}
+
+	/// Normalizes an input the same way the runner does.
+	fn prepare(&self, mut input: Vec<u8>) -> Vec<u8> {
+		normalize(&mut input, &self.normalize);
+		input
+	}
+
+	fn solve(&self, day: u32, input: Vec<u8>, query: &str) -> Result<Solved, Failure> {
+		let parts = match query_param(query, "parts") {
+			Some(parts) => parse_parts(parts)?,
+			None => vec![1, 2],
+		};
+		let input = self.prepare(input);
+		let ctx = Context::new();
+		let solved = catch_panic(|| {
+			let (init_time, mut solver) = facade::initialize(day, input, self.debug)?;
+			let parts = parts
+				.into_iter()
+				.map(|part| {
+					let (time, answer) = facade::run_part(&mut *solver, part, self.debug, &ctx)?;
+					Ok(PartResult {
+						part,
+						answer,
+						time_ns: time.as_nanos() as u64,
+					})
+				})
+				.collect::<Res<_>>()?;
+			Res::Ok(Solved {
+				day,
+				init_ns: init_time.as_nanos() as u64,
+				parts,
+			})
+		})??;
+		Ok(solved)
+	}
+
+	fn validate(&self, day: u32, query: &str) -> Result<Validated, Failure> {
+		let test = match query_param(query, "test") {
+			Some(test) => test
+				.parse()
+				.map_err(|_| Failure::bad_request(format!("Invalid test number `{test}`")))?,
+			None => 0,
+		};
+		let input = std::fs::read(input_file_name(day, test)).map_err(|_| Failure {
+			status: 404,
+			message: format!("Day {day} has no saved input for test {test}"),
+		})?;
+		let expected = std::fs::read_to_string(answer_file_name(day, test)).unwrap_or_default();
+		let expected = expected.lines().map(Answer::parse_line).collect::<Vec<_>>();
+
+		let input = self.prepare(input);
+		let ctx = Context::new();
+		let parts = catch_panic(|| {
+			let (_, mut solver) = facade::initialize(day, input, self.debug)?;
+			(1..=expected.len().max(2) as u32)
+				.map(|part| {
+					let (time, answer) = facade::run_part(&mut *solver, part, self.debug, &ctx)?;
+					let expected = expected.get(part as usize - 1).cloned().unwrap_or_default();
+					let correct = (!expected.is_none()).then(|| answer == expected);
+					Ok(PartCheck {
+						part,
+						answer,
+						expected,
+						correct,
+						time_ns: time.as_nanos() as u64,
+					})
+				})
+				.collect::<Res<Vec<_>>>()
+		})??;
+		Ok(Validated {
+			day,
+			test,
+			correct: parts.iter().all(|p| p.correct != Some(false)),
+			parts,
+		})
+	}
+
+	/// Starts benchmarking on another thread, and returns the stream of events it sends.
+	fn bench(&self, day: u32, input: Vec<u8>, query: &str) -> EventStream {
+		let (sender, receiver) = channel();
+		let this = self.clone();
+		let query = query.to_string();
+		std::thread::spawn(move || {
+			let res = catch_panic(|| this.bench_events(day, input, &query, &sender));
+			if let Err(failure) = res.and_then(|res| res) {
+				let event = BenchEvent::Error {
+					error: failure.message,
+				};
+				send(&sender, &event);
+			}
+		});
+		EventStream {
+			receiver,
+			buf: Vec::new(),
+			pos: 0,
+		}
+	}
+
+	fn bench_events(
+		&self,
+		day: u32,
+		input: Vec<u8>,
+		query: &str,
+		sender: &Sender<Vec<u8>>,
+	) -> Result<(), Failure> {
+		let count = match query_param(query, "count") {
+			Some(count) => count
+				.parse()
+				.map_err(|_| Failure::bad_request(format!("Invalid count `{count}`")))?,
+			None => self.bench_count,
+		};
+		let input = if input.is_empty() {
+			std::fs::read(input_file_name(day, 0))?
+		} else {
+			input
+		};
+		let input = self.prepare(input);
+
+		let ctx = Context::new();
+		let run_once = || -> Res<([Duration; 3], [Answer; 2])> {
+			let (t0, mut solver) = facade::initialize(day, input.clone(), self.debug)?;
+			let (t1, a1) = facade::run_part(&mut *solver, 1, self.debug, &ctx)?;
+			let (t2, a2) = facade::run_part(&mut *solver, 2, self.debug, &ctx)?;
+			Ok(([t0, t1, t2], [a1, a2]))
+		};
+
+		for _ in 0..WARMUP_RUNS {
+			run_once()?;
+		}
+
+		let start = Instant::now();
+		let mut last_progress = start;
+		let mut runs = 0;
+		let mut total = [Duration::ZERO; 3];
+		let mut min = [Duration::MAX; 3];
+		let mut answers = [Answer::None, Answer::None];
+		let more = |runs| match count {
+			0 => start.elapsed() < self.bench_time,
+			count => runs < count,
+		};
+		while more(runs) {
+			let (times, run_answers) = run_once()?;
+			for i in 0..3 {
+				total[i] += times[i];
+				min[i] = min[i].min(times[i]);
+			}
+			answers = run_answers;
+			runs += 1;
+
+			if last_progress.elapsed() >= PROGRESS_INTERVAL {
+				last_progress = Instant::now();
+				let event = BenchEvent::Progress {
+					runs,
+					elapsed_ns: start.elapsed().as_nanos() as u64,
+				};
+				if !send(sender, &event) {
+					// The client went away
+					return Ok(());
+				}
+			}
+		}
+
+		let event = BenchEvent::Done {
+			runs,
+			avg_ns: total.map(|t| (t / runs.max(1) as u32).as_nanos() as u64),
+			min_ns: min.map(|t| if runs > 0 { t.as_nanos() as u64 } else { 0 }),
+			answers,
+		};
+		send(sender, &event);
+		Ok(())
+	}
+}
+
+/// Sends an event as a line of JSON. Returns false if nobody is listening anymore.
+fn send(sender: &Sender<Vec<u8>>, event: &BenchEvent) -> bool {
+	let mut line = serde_json::to_vec(event).expect("events always serialize");
+	line.push(b'\n');
+	sender.send(line).is_ok()
+}
+
+/// The body of a streamed response, read from the thread that produces it.
+struct EventStream {
+	receiver: Receiver<Vec<u8>>,
+	buf: Vec<u8>,
+	pos: usize,
+}
+
+impl Read for EventStream {
+	fn read(&mut self, out: &mut [u8]) -> std::io::Result<usize> {
+		if self.pos == self.buf.len() {
+			// The stream ends when the sender is dropped
+			let Ok(buf) = self.receiver.recv() else {
+				return Ok(0);
+			};
+			self.buf = buf;
+			self.pos = 0;
+		}
+		let n = out.len().min(self.buf.len() - self.pos);
+		out[..n].copy_from_slice(&self.buf[self.pos..self.pos + n]);
+		self.pos += n;
+		Ok(n)
+	}
+}
+
+fn days() -> Vec<DayInfo> {
+	facade::days()
+		.map(|day| DayInfo {
+			day,
+			input: input_file_name(day, 0).exists(),
+			answers: std::fs::metadata(answer_file_name(day, 0)).is_ok_and(|m| m.len() > 0),
+		})
+		.collect()
+}
+
+fn route(method: &Method, path: &str) -> Option<Route> {
+	let mut segments = path.trim_matches('/').split('/');
+	let (name, day) = (segments.next()?, segments.next());
+	if segments.next().is_some() {
+		return None;
+	}
+	let day = match day {
+		Some(day) => Some(day.parse().ok()?),
+		None => None,
+	};
+	Some(match (method, name, day) {
+		(Method::Get, "days", None) => Route::Days,
+		(Method::Post, "solve", Some(day)) => Route::Solve(day),
+		(Method::Get, "validate", Some(day)) => Route::Validate(day),
+		(Method::Post, "bench", Some(day)) => Route::Bench(day),
+		_ => return None,
+	})
+}
+
+/// Finds the value of `name` in a query string like `a=1&b=2`.
+fn query_param<'a>(query: &'a str, name: &str) -> Option<&'a str> {
+	query
+		.split('&')
+		.filter_map(|pair| pair.split_once('='))
+		.find_map(|(key, value)| (key == name).then_some(value))
+}
+
+/// Parses a comma-separated list of parts, like `1,2`.
+fn parse_parts(parts: &str) -> Result<Vec<u32>, Failure> {
+	parts
+		.split(',')
+		.map(|part| match part.parse() {
+			Ok(0) | Err(_) => Err(Failure::bad_request(format!("Invalid part `{part}`"))),
+			Ok(part) => Ok(part),
+		})
+		.collect()
+}
+
+/// Runs a solver, turning a panic into an error response so the server keeps going.
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, Failure> {
+	catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+		let message = payload
+			.downcast_ref::<&str>()
+			.map(|s| s.to_string())
+			.or_else(|| payload.downcast_ref::<String>().cloned())
+			.unwrap_or_default();
+		Failure {
+			status: 500,
+			message: format!("Solver panicked: {message}"),
+		}
+	})
+}
+
+fn read_body(request: &mut Request) -> Result<Vec<u8>, Failure> {
+	let mut body = Vec::new();
+	request.as_reader().read_to_end(&mut body)?;
+	Ok(body)
+}
+
+fn json(value: &impl Serialize) -> Result<String, Failure> {
+	serde_json::to_string(value).map_err(|e| Failure {
+		status: 500,
+		message: e.to_string(),
+	})
+}
+
+fn json_header() -> Header {
+	Header::from_bytes("Content-Type", "application/json").unwrap()
+}
+
+fn respond<R: Read>(request: Request, response: Response<R>) {
+	if let Err(e) = request.respond(response) {
+		eprintln!("Couldn't send response: {e}");
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn routes() {
+		assert_eq!(route(&Method::Get, "/days"), Some(Route::Days));
+		assert_eq!(route(&Method::Post, "/solve/3"), Some(Route::Solve(3)));
+		assert_eq!(
+			route(&Method::Get, "/validate/12/"),
+			Some(Route::Validate(12))
+		);
+		assert_eq!(route(&Method::Post, "/bench/25"), Some(Route::Bench(25)));
+		assert_eq!(route(&Method::Get, "/solve/3"), None);
+		assert_eq!(route(&Method::Post, "/solve/x"), None);
+		assert_eq!(route(&Method::Post, "/solve/3/1"), None);
+	}
+
+	#[test]
+	fn queries() {
+		assert_eq!(query_param("parts=1,2&test=3", "test"), Some("3"));
+		assert_eq!(query_param("parts=1,2", "test"), None);
+		assert_eq!(parse_parts("1,2").unwrap(), [1, 2]);
+		assert!(parse_parts("0").is_err());
+		assert!(parse_parts("1,").is_err());
+	}
+}
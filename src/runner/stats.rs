@@ -0,0 +1,171 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use super::readable_time;
+use crate::helpers::HashMap;
+use crate::{AocError, Res};
+
+pub(super) const README: &str = "./README.md";
+const START_MARKER: &str = "<!-- leaderboard start -->";
+const END_MARKER: &str = "<!-- leaderboard end -->";
+
+/// One row of the personal stats page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct DayStats {
+	pub day: u32,
+	pub parts: [Option<PartStats>; 2],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct PartStats {
+	/// Kept as text because it can be `>24h`.
+	pub time: String,
+	pub rank: u32,
+	pub score: u32,
+}
+
+/// Parses the table on the personal stats page (`/leaderboard/self`). Days without stars are
+/// left out, and a page without any rows is an error so it can't blank the README's table.
+pub(super) fn parse_personal_stats(html: &str) -> Res<Vec<DayStats>> {
+	let start = html.find("<pre").unwrap_or(0);
+	let end = html[start..]
+		.find("</pre>")
+		.map_or(html.len(), |e| start + e);
+	let table = strip_tags(&html[start..end]);
+
+	let stats: Vec<DayStats> = table
+		.lines()
+		.filter_map(|line| {
+			let fields: Vec<&str> = line.split_whitespace().collect();
+			let &[day, ref rest @ ..] = &fields[..] else {
+				return None;
+			};
+			let day = day.parse().ok()?;
+			let part = |i: usize| {
+				let &[time, rank, score] = rest.get(i * 3..i * 3 + 3)? else {
+					return None;
+				};
+				Some(PartStats {
+					time: time.to_string(),
+					rank: rank.parse().ok()?,
+					score: score.parse().ok()?,
+				})
+			};
+			Some(DayStats {
+				day,
+				parts: [part(0), part(1)],
+			})
+		})
+		.collect();
+
+	if stats.is_empty() {
+		return Err(AocError::EmptyStats);
+	}
+	Ok(stats)
+}
+
+fn strip_tags(html: &str) -> String {
+	let mut text = String::with_capacity(html.len());
+	let mut in_tag = false;
+	for c in html.chars() {
+		match c {
+			'<' => in_tag = true,
+			'>' if in_tag => in_tag = false,
+			c if !in_tag => text.push(c),
+			_ => (),
+		}
+	}
+	html_escape::decode_html_entities(&text).into_owned()
+}
+
+/// Renders the stats in the same layout as the stats page, with an extra column for local
+/// benchmark medians if there are any.
+pub(super) fn render_table(stats: &[DayStats], medians: &HashMap<u32, Duration>) -> String {
+	let bench = !medians.is_empty();
+	let mut out = String::new();
+	out += "      --------Part 1--------   --------Part 2--------\n";
+	out += "Day       Time   Rank  Score       Time   Rank  Score";
+	if bench {
+		out += "        Bench";
+	}
+	out += "\n";
+
+	for day in stats {
+		write!(out, "{:>3}", day.day).unwrap();
+		for part in &day.parts {
+			match part {
+				Some(p) => write!(out, "   {:>8} {:>6} {:>6}", p.time, p.rank, p.score).unwrap(),
+				None => write!(out, "   {:>8} {:>6} {:>6}", "-", "-", "-").unwrap(),
+			}
+		}
+		if let Some(&median) = medians.get(&day.day) {
+			write!(out, "   {:>10}", readable_time(median, 3)).unwrap();
+		}
+		out += "\n";
+	}
+	out
+}
+
+/// Replaces everything between the leaderboard markers in the README with `table`, inside a code
+/// block.
+pub(super) fn update_readme(readme: &str, table: &str) -> Res<String> {
+	let (before, rest) = readme
+		.split_once(START_MARKER)
+		.ok_or(AocError::MissingMarkers)?;
+	let (_, after) = rest
+		.split_once(END_MARKER)
+		.ok_or(AocError::MissingMarkers)?;
+	Ok(format!(
+		"{before}{START_MARKER}\n```ruby\n{table}```\n{END_MARKER}{after}"
+	))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const PAGE: &str = "<article><p>These are your personal leaderboard statistics.</p>
+<pre>      <span class=\"leaderboard-daydesc-first\">--------Part 1--------</span>   <span class=\"leaderboard-daydesc-both\">--------Part 2--------</span>
+Day   <span class=\"leaderboard-daydesc-first\">    Time   Rank  Score</span>   <span class=\"leaderboard-daydesc-both\">    Time   Rank  Score</span>
+ 25   00:38:42    920      0   00:38:45    799      0
+  8   20:29:04  55920      0       &gt;24h  52690      0
+  7   15:03:42  46353      0          -      -      -
+</pre>
+</article>";
+
+	#[test]
+	fn parse_stats() {
+		let stats = parse_personal_stats(PAGE).unwrap();
+		assert_eq!(stats.len(), 3);
+		assert_eq!(stats[0].day, 25);
+		assert_eq!(
+			stats[0].parts[1],
+			Some(PartStats {
+				time: "00:38:45".to_string(),
+				rank: 799,
+				score: 0
+			})
+		);
+		assert_eq!(stats[1].parts[1].as_ref().unwrap().time, ">24h");
+		assert_eq!(stats[2].parts[1], None);
+	}
+
+	#[test]
+	fn empty_stats_are_an_error() {
+		let page = "<article><p>You haven't collected any stars yet.</p></article>";
+		assert!(matches!(
+			parse_personal_stats(page),
+			Err(AocError::EmptyStats)
+		));
+	}
+
+	#[test]
+	fn replace_between_markers() {
+		let readme = "# Title\n<!-- leaderboard start -->\nold\n<!-- leaderboard end -->\nrest\n";
+		let updated = update_readme(readme, "new\n").unwrap();
+		assert_eq!(
+			updated,
+			"# Title\n<!-- leaderboard start -->\n```ruby\nnew\n```\n<!-- leaderboard end -->\nrest\n"
+		);
+	}
+}
@@ -0,0 +1,430 @@
+//! The interactive front end. Days are run by starting this runner again as a child process, so
+//! a solver's stdout and stderr can be shown in their own panes and a panic doesn't take down the
+//! interface.
+
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::time::Duration;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::terminal::{
+	disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use crossterm::ExecutableCommand;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+
+use super::cache::AnswerCache;
+use super::{answer_file_name, input_file_name, prompt};
+use crate::Res;
+
+const HELP: &str = " ↑↓ select  r run  v validate  e next example  t toggle example input  \
+	d debug level  PgUp/PgDn scroll prompt  q quit";
+
+/// What the days list shows about a day.
+struct DayStatus {
+	day: u32,
+	compiled: bool,
+	input: bool,
+	answers: bool,
+	/// Total of the most recently cached part times
+	time: Option<Duration>,
+}
+
+struct App {
+	exe: PathBuf,
+	debug: u8,
+	days: Vec<DayStatus>,
+	list: ListState,
+	/// The selected day's prompt as Markdown, and its example inputs.
+	prompt: String,
+	examples: Vec<(String, String)>,
+	/// Index into `examples` of the one that is shown.
+	example: usize,
+	/// Run on the shown example instead of the real input.
+	use_example: bool,
+	prompt_scroll: u16,
+	/// The last command and what it printed.
+	label: String,
+	stdout: String,
+	stderr: String,
+	running: bool,
+	sender: Sender<(String, std::io::Result<Output>)>,
+	results: Receiver<(String, std::io::Result<Output>)>,
+}
+
+/// Runs the interface until the user quits. The terminal is restored even if drawing fails.
+pub(super) fn run(debug: u8) -> Res<()> {
+	let mut app = App::new(debug)?;
+
+	enable_raw_mode()?;
+	std::io::stdout().execute(EnterAlternateScreen)?;
+	let mut terminal = Terminal::new(CrosstermBackend::new(std::io::stdout()))?;
+	let result = app.event_loop(&mut terminal);
+	disable_raw_mode()?;
+	std::io::stdout().execute(LeaveAlternateScreen)?;
+	result
+}
+
+impl App {
+	fn new(debug: u8) -> Res<Self> {
+		let (sender, results) = channel();
+		let mut app = Self {
+			exe: std::env::current_exe()?,
+			debug,
+			days: Vec::new(),
+			list: ListState::default().with_selected(Some(0)),
+			prompt: String::new(),
+			examples: Vec::new(),
+			example: 0,
+			use_example: false,
+			prompt_scroll: 0,
+			label: String::new(),
+			stdout: String::new(),
+			stderr: String::new(),
+			running: false,
+			sender,
+			results,
+		};
+		app.refresh()?;
+		app.load_day();
+		Ok(app)
+	}
+
+	fn event_loop(&mut self, terminal: &mut Terminal<impl Backend>) -> Res<()> {
+		loop {
+			terminal.draw(|frame| self.draw(frame))?;
+
+			if let Ok((label, output)) = self.results.try_recv() {
+				self.finish(label, output)?;
+			}
+
+			if !event::poll(Duration::from_millis(100))? {
+				continue;
+			}
+			let Event::Key(key) = event::read()? else {
+				continue;
+			};
+			if key.kind != KeyEventKind::Press {
+				continue;
+			}
+			match key.code {
+				KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+				KeyCode::Up | KeyCode::Char('k') => self.select(-1),
+				KeyCode::Down | KeyCode::Char('j') => self.select(1),
+				KeyCode::Enter | KeyCode::Char('r') => self.start("run"),
+				KeyCode::Char('v') => self.start("validate"),
+				KeyCode::Char('e') if !self.examples.is_empty() => {
+					self.example = (self.example + 1) % self.examples.len();
+				}
+				KeyCode::Char('t') => self.use_example = !self.use_example,
+				KeyCode::Char('d') => self.debug = (self.debug + 1) % 4,
+				KeyCode::PageDown => self.prompt_scroll = self.prompt_scroll.saturating_add(10),
+				KeyCode::PageUp => self.prompt_scroll = self.prompt_scroll.saturating_sub(10),
+				_ => (),
+			}
+		}
+	}
+
+	fn selected_day(&self) -> u32 {
+		self.days[self.list.selected().unwrap_or(0)].day
+	}
+
+	fn select(&mut self, step: isize) {
+		let len = self.days.len() as isize;
+		let current = self.list.selected().unwrap_or(0) as isize;
+		self.list
+			.select(Some((current + step).rem_euclid(len) as usize));
+		self.load_day();
+	}
+
+	/// Reads the status of every day from the inputs directory and the answer cache.
+	fn refresh(&mut self) -> Res<()> {
+		let cache = AnswerCache::load()?;
+		self.days = (1..=25)
+			.map(|day| {
+				let times: Vec<_> = (1..=2)
+					.filter_map(|part| cache.latest(day, part))
+					.map(|entry| entry.time())
+					.collect();
+				DayStatus {
+					day,
+					compiled: crate::days::is_compiled(day),
+					input: input_file_name(day, 0).exists(),
+					answers: std::fs::metadata(answer_file_name(day, 0)).is_ok_and(|m| m.len() > 0),
+					time: (!times.is_empty()).then(|| times.iter().sum()),
+				}
+			})
+			.collect();
+		Ok(())
+	}
+
+	/// Loads the prompt and examples of the selected day.
+	fn load_day(&mut self) {
+		let day = self.selected_day();
+		self.prompt = match std::fs::read_to_string(prompt(day)) {
+			Ok(html) => prompt_markdown(&html),
+			Err(_) => "No prompt saved, fetch it with `--mode prompt`".to_string(),
+		};
+		self.prompt_scroll = 0;
+		self.examples = (1..=u8::MAX)
+			.map_while(|test| {
+				let path = input_file_name(day, test);
+				let input = std::fs::read_to_string(&path).ok()?;
+				let answer =
+					std::fs::read_to_string(answer_file_name(day, test)).unwrap_or_default();
+				let name = path.file_name().unwrap().to_string_lossy().into_owned();
+				let answer = answer.lines().collect::<Vec<_>>().join(", ");
+				Some((format!("{name} (answers: {answer})"), input))
+			})
+			.collect();
+		self.example = 0;
+	}
+
+	/// Runs the selected day in `mode` on a background thread.
+	fn start(&mut self, mode: &str) {
+		if self.running {
+			return;
+		}
+		let day = self.selected_day();
+		let test = if self.use_example && !self.examples.is_empty() {
+			self.example + 1
+		} else {
+			0
+		};
+
+		let mut command = Command::new(&self.exe);
+		command
+			.arg(day.to_string())
+			.args(["--mode", mode, "--test", &test.to_string()])
+			.stdin(Stdio::null());
+		for _ in 0..self.debug {
+			command.arg("--debug");
+		}
+
+		self.label = format!("{mode} day {day}");
+		if test > 0 {
+			self.label += &format!(" on example {test}");
+		}
+		self.running = true;
+		let label = self.label.clone();
+		let sender = self.sender.clone();
+		std::thread::spawn(move || sender.send((label, command.output())));
+	}
+
+	fn finish(&mut self, label: String, output: std::io::Result<Output>) -> Res<()> {
+		self.running = false;
+		match output {
+			Ok(output) => {
+				self.label = format!("{label}, {}", output.status);
+				self.stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+				self.stderr = clean_stderr(&String::from_utf8_lossy(&output.stderr));
+			}
+			Err(e) => {
+				self.label = format!("{label}, couldn't start");
+				self.stdout.clear();
+				self.stderr = e.to_string();
+			}
+		}
+		// Runs can save answers and fill the cache
+		self.refresh()
+	}
+
+	fn draw(&mut self, frame: &mut Frame) {
+		let [main, bottom, help] = split(
+			frame.size(),
+			Direction::Vertical,
+			[
+				Constraint::Percentage(60),
+				Constraint::Min(5),
+				Constraint::Length(1),
+			],
+		);
+		let [days, prompt, examples] = split(
+			main,
+			Direction::Horizontal,
+			[
+				Constraint::Length(26),
+				Constraint::Percentage(55),
+				Constraint::Min(10),
+			],
+		);
+		let [output, debug] = split(
+			bottom,
+			Direction::Horizontal,
+			[Constraint::Percentage(50), Constraint::Percentage(50)],
+		);
+
+		let items: Vec<ListItem> = self
+			.days
+			.iter()
+			.map(|status| {
+				ListItem::new(format!(
+					"{:02} {:<5} {:<7} {}",
+					status.day,
+					if status.input { "input" } else { "" },
+					if status.answers { "answers" } else { "" },
+					match status.time {
+						_ if !status.compiled => "compiled out".to_string(),
+						Some(t) => format!("{t:.1?}"),
+						None => String::new(),
+					},
+				))
+			})
+			.collect();
+		let list = List::new(items)
+			.block(titled("Days"))
+			.highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+		frame.render_stateful_widget(list, days, &mut self.list);
+
+		frame.render_widget(
+			Paragraph::new(self.prompt.as_str())
+				.block(titled("Prompt"))
+				.wrap(Wrap { trim: false })
+				.scroll((self.prompt_scroll, 0)),
+			prompt,
+		);
+
+		let (title, example) = match self.examples.get(self.example) {
+			Some((name, input)) => (name.clone(), input.as_str()),
+			None => ("Examples".to_string(), "No examples saved"),
+		};
+		let title = if self.use_example {
+			format!("{title}, used for runs")
+		} else {
+			title
+		};
+		frame.render_widget(Paragraph::new(example).block(titled(&title)), examples);
+
+		let output_title = if self.running {
+			format!("Output: running {}", self.label)
+		} else if self.label.is_empty() {
+			"Output".to_string()
+		} else {
+			format!("Output: {}", self.label)
+		};
+		frame.render_widget(tail(&self.stdout, &output_title, output), output);
+		frame.render_widget(
+			tail(
+				&self.stderr,
+				&format!("Debug output (level {})", self.debug),
+				debug,
+			),
+			debug,
+		);
+
+		frame.render_widget(Paragraph::new(HELP), help);
+	}
+}
+
+fn split<const N: usize>(
+	area: Rect,
+	direction: Direction,
+	constraints: [Constraint; N],
+) -> [Rect; N] {
+	let areas = Layout::default()
+		.direction(direction)
+		.constraints(constraints)
+		.split(area);
+	std::array::from_fn(|i| areas[i])
+}
+
+fn titled(title: &str) -> Block<'_> {
+	Block::default().borders(Borders::ALL).title(title)
+}
+
+/// A pane scrolled to the end of `text`.
+fn tail<'a>(text: &'a str, title: &'a str, area: Rect) -> Paragraph<'a> {
+	let lines = text.lines().count() as u16;
+	let visible = area.height.saturating_sub(2);
+	Paragraph::new(text)
+		.block(titled(title))
+		.scroll((lines.saturating_sub(visible), 0))
+}
+
+/// Keeps what the progress bar last drew on each line, without the escape codes.
+fn clean_stderr(stderr: &str) -> String {
+	stderr
+		.lines()
+		.map(|line| {
+			line.rsplit('\r')
+				.next()
+				.unwrap_or(line)
+				.replace("\x1b[K", "")
+		})
+		.filter(|line| !line.is_empty())
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+/// Converts the puzzle descriptions on a saved prompt page into Markdown.
+fn prompt_markdown(html: &str) -> String {
+	let mut out = String::new();
+	let mut rest = html;
+	let mut in_article = false;
+	let mut in_pre = false;
+
+	while let Some(start) = rest.find('<') {
+		if in_article {
+			out += &html_escape::decode_html_entities(&rest[..start]);
+		}
+		let Some(end) = rest[start..].find('>') else {
+			break;
+		};
+		let tag = &rest[start + 1..start + end];
+		rest = &rest[start + end + 1..];
+
+		match tag.split_whitespace().next().unwrap_or_default() {
+			"article" => in_article = true,
+			"/article" => {
+				in_article = false;
+				out += "\n";
+			}
+			_ if !in_article => (),
+			"h2" => out += "## ",
+			"/h2" | "/p" => out += "\n\n",
+			"pre" => {
+				in_pre = true;
+				out += "```\n";
+			}
+			"/pre" => {
+				in_pre = false;
+				out += "```\n\n";
+			}
+			"code" | "/code" if !in_pre => out += "`",
+			"em" | "/em" => out += "*",
+			"li" => out += "- ",
+			_ => (),
+		}
+	}
+
+	while out.contains("\n\n\n") {
+		out = out.replace("\n\n\n", "\n\n");
+	}
+	out.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn markdown_from_prompt() {
+		let html = "<main><p>Not this</p><article class=\"day-desc\"><h2>--- Day 1: Trebuchet?! \
+			---</h2><p>The <em>calibration</em> value is <code>12</code>.</p>\n<pre><code>1abc2\n\
+			</code></pre>\n</article></main>";
+		assert_eq!(
+			prompt_markdown(html),
+			"## --- Day 1: Trebuchet?! ---\n\nThe *calibration* value is `12`.\n\n```\n1abc2\n```"
+		);
+	}
+
+	#[test]
+	fn progress_bar_is_collapsed() {
+		assert_eq!(
+			clean_stderr("start\n\r10%\x1b[K\r50%\x1b[K\r\x1b[K\ndone\n"),
+			"start\ndone"
+		);
+	}
+}
@@ -1,17 +1,17 @@
-use std::fmt::{Display, Write};
+use std::fmt::Write;
 
 use std::time::Duration;
 
 use crate::runner::time_fn;
-use crate::Res;
+use crate::{Answer, Context, Res};
 
 /// Trait to be implemented for each day.
 #[allow(unused_variables)]
 pub trait Solver: Sized {
 	/// The type returned from part one.
-	type AnswerOne: Sized + Display;
+	type AnswerOne: Into<Answer>;
 	/// The type returned from part two.
-	type AnswerTwo: Sized + Display;
+	type AnswerTwo: Into<Answer>;
 
 	/// Like [`Default`] but takes a file. Used to perform operations to prepare for part one or
 	/// part two. This takes a [`Vec`] so that the buffer can be reused and modified. It will likely
@@ -19,10 +19,16 @@ pub trait Solver: Sized {
 	fn initialize(file: Vec<u8>, dbg: u8) -> Self;
 
 	/// Runs part one. This will always be called after [`initialize`](Solver::initialize).
-	fn part_one(&mut self, dbg: u8) -> Self::AnswerOne;
+	///
+	/// Long-running parts can report progress to `ctx` and should return early once
+	/// [`Context::is_cancelled`] is true.
+	fn part_one(&mut self, dbg: u8, ctx: &Context) -> Self::AnswerOne;
 
 	/// Runs part two. This will always be called after [`initialize`](Solver::initialize).
-	fn part_two(&mut self, dbg: u8) -> Self::AnswerTwo;
+	///
+	/// Long-running parts can report progress to `ctx` and should return early once
+	/// [`Context::is_cancelled`] is true.
+	fn part_two(&mut self, dbg: u8, ctx: &Context) -> Self::AnswerTwo;
 
 	/// Runs parts other than one and two, and writes the result plus a newline into a writer. This
 	/// will always be called after [`initialize`](Solver::initialize) and won't include `1` or `2`.
@@ -33,49 +39,59 @@ pub trait Solver: Sized {
 	/// Runs parts one and two. This includes a call to [`initialize`](Solver::initialize). This
 	/// will be used for full benchmarking.
 	fn run_both(file: Vec<u8>, dbg: u8) -> (Self::AnswerOne, Self::AnswerTwo) {
+		let ctx = Context::new();
 		let mut sol = Self::initialize(file, dbg);
-		(sol.part_one(dbg), sol.part_two(dbg))
+		(sol.part_one(dbg, &ctx), sol.part_two(dbg, &ctx))
 	}
 
-	/// Same as `run_both` but returns timing info and results as strings.
-	fn run_both_string(file: Vec<u8>, dbg: u8) -> (Duration, String, String) {
+	/// Same as `run_both` but returns timing info and results as [`Answer`]s.
+	fn run_both_answers(file: Vec<u8>, dbg: u8) -> (Duration, Answer, Answer) {
 		let (time, (p1, p2)) = time_fn(|| Self::run_both(file, dbg));
-		(time, p1.to_string(), p2.to_string())
+		(time, p1.into(), p2.into())
+	}
+
+	/// Same as `run_both_answers` but times [`initialize`](Solver::initialize), part one, and part
+	/// two separately.
+	fn run_both_timed(file: Vec<u8>, dbg: u8) -> ([Duration; 3], Answer, Answer) {
+		let ctx = Context::new();
+		let (t0, mut sol) = time_fn(|| Self::initialize(file, dbg));
+		let (t1, p1) = time_fn(|| sol.part_one(dbg, &ctx).into());
+		let (t2, p2) = time_fn(|| sol.part_two(dbg, &ctx).into());
+		([t0, t1, t2], p1, p2)
 	}
 }
 
 /// Object-safe version of [`Solver`].
 pub trait SolverSafe {
 	/// Runs part one. This will always be called after [`initialize`](Solver::initialize).
-	fn part_one(&mut self, dbg: u8, writer: &mut String) -> Duration;
+	fn part_one(&mut self, dbg: u8, ctx: &Context) -> (Duration, Answer);
 
 	/// Runs part two. This will always be called after [`initialize`](Solver::initialize).
-	fn part_two(&mut self, dbg: u8, writer: &mut String) -> Duration;
+	fn part_two(&mut self, dbg: u8, ctx: &Context) -> (Duration, Answer);
 
-	/// Runs parts other than one and two, and writes the result plus a newline into a writer. This
-	/// will always be called after [`initialize`](Solver::initialize) and won't include `1` or `2`.
+	/// Runs parts other than one and two. This will always be called after
+	/// [`initialize`](Solver::initialize) and won't include `1` or `2`. What the part writes is
+	/// read back with [`Answer::parse_line`].
 	///
 	/// Returns `Err(())` if this part is unimplemented.
-	fn run_any(&mut self, part: u32, dbg: u8, writer: &mut String) -> Res<Duration>;
+	fn run_any(&mut self, part: u32, dbg: u8) -> Res<(Duration, Answer)>;
 }
 
 impl<T> SolverSafe for T
 where
 	T: Solver,
 {
-	fn part_one(&mut self, dbg: u8, writer: &mut String) -> Duration {
-		let (time, a1) = time_fn(|| self.part_one(dbg));
-		write!(writer, "{a1}").unwrap();
-		time
+	fn part_one(&mut self, dbg: u8, ctx: &Context) -> (Duration, Answer) {
+		time_fn(|| self.part_one(dbg, ctx).into())
 	}
 
-	fn part_two(&mut self, dbg: u8, writer: &mut String) -> Duration {
-		let (time, a2) = time_fn(|| self.part_two(dbg));
-		write!(writer, "{a2}").unwrap();
-		time
+	fn part_two(&mut self, dbg: u8, ctx: &Context) -> (Duration, Answer) {
+		time_fn(|| self.part_two(dbg, ctx).into())
 	}
 
-	fn run_any(&mut self, part: u32, dbg: u8, writer: &mut String) -> Res<Duration> {
-		self.run_any(part, writer, dbg)
+	fn run_any(&mut self, part: u32, dbg: u8) -> Res<(Duration, Answer)> {
+		let mut output = String::new();
+		let time = self.run_any(part, &mut output, dbg)?;
+		Ok((time, Answer::parse_line(output.trim_end())))
 	}
 }
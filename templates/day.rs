@@ -0,0 +1,40 @@
+#![cfg(feature = "day{{day}}")]
+
+use crate::helpers::*;
+
+pub type A1 = u64;
+pub type A2 = u64;
+
+#[derive(Debug, Default, Clone)]
+pub struct Solution {
+	file: Vec<u8>,
+}
+
+impl Solver for Solution {
+	type AnswerOne = A1;
+	type AnswerTwo = A2;
+
+	fn initialize(file: Vec<u8>, _: u8) -> Self {
+		Self { file }
+	}
+
+	fn part_one(&mut self, _: u8, _: &Context) -> Self::AnswerOne {
+		0
+	}
+
+	fn part_two(&mut self, _: u8, _: &Context) -> Self::AnswerTwo {
+		0
+	}
+
+	fn run_any<W: std::fmt::Write>(
+		&mut self,
+		part: u32,
+		_writer: W,
+		_: u8,
+	) -> Res<std::time::Duration> {
+		#[allow(clippy::match_single_binding)]
+		match part {
+			_ => Err(AocError::PartNotFound),
+		}
+	}
+}